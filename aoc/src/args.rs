@@ -0,0 +1,201 @@
+use std::path::PathBuf;
+
+const USAGE: &str = "Usage: aoc <day|all> [--part <1|2>] [--input-dir <dir>] [--input <file|->] [--verify] [--record] [--bench <runs>]";
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DaySelection {
+    All,
+    Day(u8),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Args {
+    pub days: DaySelection,
+    /// The part to run, or None to run both parts.
+    pub part: Option<u8>,
+    /// Directory containing the `<day>.txt` input files, overriding the default lookup.
+    pub input_dir: Option<PathBuf>,
+    /// Explicit input file for a single day, where `-` means stdin.
+    pub input_file: Option<PathBuf>,
+    /// Compare the answers with the recorded answers.
+    pub verify: bool,
+    /// Record the answers as the correct answers.
+    pub record: bool,
+    /// Run every part this many times and compare the timings with the previous benchmark.
+    pub bench: Option<usize>,
+}
+
+impl Args {
+    /// Parses the command line arguments (without the program name).
+    /// Returns a message that can be shown to the user when the arguments are invalid.
+    pub fn parse<I>(args: I) -> Result<Args, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut days: Option<DaySelection> = None;
+        let mut part: Option<u8> = None;
+        let mut input_dir: Option<PathBuf> = None;
+        let mut input_file: Option<PathBuf> = None;
+        let mut verify = false;
+        let mut record = false;
+        let mut bench: Option<usize> = None;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" | "-p" => {
+                    let value = args.next().ok_or("Missing value for --part")?;
+                    part = Some(parse_part(&value)?);
+                }
+                "--input-dir" => {
+                    let value = args.next().ok_or("Missing value for --input-dir")?;
+                    input_dir = Some(PathBuf::from(value));
+                }
+                "--input" | "-i" => {
+                    let value = args.next().ok_or("Missing value for --input")?;
+                    input_file = Some(PathBuf::from(value));
+                }
+                "--bench" => {
+                    let value = args.next().ok_or("Missing value for --bench")?;
+                    bench = Some(parse_nr_runs(&value)?);
+                }
+                "--verify" => verify = true,
+                "--record" => record = true,
+                "all" => days = Some(DaySelection::All),
+                _ => days = Some(DaySelection::Day(parse_day(&arg)?)),
+            }
+        }
+
+        let days = days.ok_or(USAGE)?;
+        if days == DaySelection::All && input_file.is_some() {
+            return Err("--input can only be used when running a single day".to_string());
+        }
+        // The recorded answers belong to the real inputs, so other inputs can't be checked against them.
+        if record && input_file.is_some() {
+            return Err("--record can't be combined with --input".to_string());
+        }
+        if verify && input_file.is_some() {
+            return Err("--verify can't be combined with --input".to_string());
+        }
+
+        Ok(Args {
+            days,
+            part,
+            input_dir,
+            input_file,
+            verify,
+            record,
+            bench,
+        })
+    }
+}
+
+fn parse_day(text: &str) -> Result<u8, String> {
+    match text.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("Invalid day '{text}'\n{USAGE}")),
+    }
+}
+
+fn parse_part(text: &str) -> Result<u8, String> {
+    match text {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("Invalid part '{text}', expected 1 or 2")),
+    }
+}
+
+fn parse_nr_runs(text: &str) -> Result<usize, String> {
+    match text.parse::<usize>() {
+        Ok(nr_runs) if nr_runs > 0 => Ok(nr_runs),
+        _ => Err(format!("Invalid number of runs '{text}'")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[rstest]
+    #[case(&["7"], DaySelection::Day(7), None)]
+    #[case(&["07"], DaySelection::Day(7), None)]
+    #[case(&["7", "--part", "2"], DaySelection::Day(7), Some(2))]
+    #[case(&["--part", "1", "12"], DaySelection::Day(12), Some(1))]
+    #[case(&["all"], DaySelection::All, None)]
+    #[case(&["all", "-p", "1"], DaySelection::All, Some(1))]
+    fn test_parse(
+        #[case] args: &[&str],
+        #[case] expected_days: DaySelection,
+        #[case] expected_part: Option<u8>,
+    ) {
+        // Act
+        let actual = Args::parse(to_args(args)).unwrap();
+
+        // Assert
+        let expected = Args {
+            days: expected_days,
+            part: expected_part,
+            input_dir: None,
+            input_file: None,
+            verify: false,
+            record: false,
+            bench: None,
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_input_options() {
+        // Act
+        let actual = Args::parse(to_args(&["5", "--input-dir", "inputs", "--input", "-"])).unwrap();
+
+        // Assert
+        assert_eq!(actual.input_dir, Some(PathBuf::from("inputs")));
+        assert_eq!(actual.input_file, Some(PathBuf::from("-")));
+    }
+
+    #[test]
+    fn test_parse_verify_and_record() {
+        // Act
+        let actual = Args::parse(to_args(&["all", "--verify", "--record"])).unwrap();
+
+        // Assert
+        assert!(actual.verify);
+        assert!(actual.record);
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        // Act
+        let actual = Args::parse(to_args(&["8", "--bench", "10"])).unwrap();
+
+        // Assert
+        assert_eq!(actual.bench, Some(10));
+    }
+
+    #[rstest]
+    #[case(&[])]
+    #[case(&["0"])]
+    #[case(&["26"])]
+    #[case(&["seven"])]
+    #[case(&["7", "--part"])]
+    #[case(&["7", "--part", "3"])]
+    #[case(&["7", "--input"])]
+    #[case(&["all", "--input", "-"])]
+    #[case(&["7", "--input", "-", "--record"])]
+    #[case(&["7", "--verify", "--input", "sample.txt"])]
+    #[case(&["7", "--bench", "0"])]
+    fn test_parse_invalid(#[case] args: &[&str]) {
+        // Act
+        let actual = Args::parse(to_args(args));
+
+        // Assert
+        assert!(actual.is_err());
+    }
+}
@@ -0,0 +1,293 @@
+mod args;
+
+use args::{Args, DaySelection};
+use common::{
+    resolve_input_dir, time, AnswerStore, BenchmarkHistory, DynSolution, InputError, InputReader,
+    ParsedSolution, Phase, TimingStats, VerificationReport, ANSWERS_FILE_NAME, BENCHMARK_FILE_NAME,
+};
+use std::path::Path;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let solutions = solutions();
+    let selected: Vec<&dyn DynSolution> = match args.days {
+        DaySelection::All => solutions.iter().map(|solution| solution.as_ref()).collect(),
+        DaySelection::Day(day) => match find_solution(&solutions, day) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("No solution registered for day {day}");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    let input_dir = resolve_input_dir(args.input_dir.as_deref());
+    let answers_path = input_dir.join(ANSWERS_FILE_NAME);
+    let mut answer_store = if args.verify || args.record {
+        match AnswerStore::load(&answers_path) {
+            Ok(answer_store) => answer_store,
+            Err(message) => {
+                eprintln!("{message}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        AnswerStore::new()
+    };
+    let mut report = VerificationReport::new();
+
+    // Timings of other inputs would poison the baseline of the real inputs, so they aren't stored.
+    let records_benchmarks = args.bench.is_some() && args.input_file.is_none();
+    let benchmarks_path = input_dir.join(BENCHMARK_FILE_NAME);
+    let mut history = if records_benchmarks {
+        match BenchmarkHistory::load(&benchmarks_path) {
+            Ok(history) => history,
+            Err(message) => {
+                eprintln!("{message}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        BenchmarkHistory::new()
+    };
+    let baseline = history.clone();
+    let nr_runs = args.bench.unwrap_or(1);
+
+    let mut exit_code = ExitCode::SUCCESS;
+    for solution in selected {
+        let day = solution.day();
+        let input_reader = match read_input(&args, day) {
+            Ok(input_reader) => input_reader,
+            Err(error) => {
+                eprintln!("Day {day:02}: {error}");
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let input = input_reader.input();
+        let (parsed, parse_stats) = parse_input(solution, &input, nr_runs);
+        println!(
+            "Day {day:02} (parse {})",
+            describe_timing(&parse_stats, baseline.get(day, Phase::Parse))
+        );
+        if records_benchmarks {
+            history.set(day, Phase::Parse, parse_stats);
+        }
+
+        for part in selected_parts(args.part) {
+            let Some((answer, stats)) = run_part(&parsed, part, nr_runs) else {
+                continue;
+            };
+            let phase = Phase::for_part(part).unwrap();
+            println!(
+                "Part {part}: {answer} ({})",
+                describe_timing(&stats, baseline.get(day, phase))
+            );
+            if records_benchmarks {
+                history.set(day, phase, stats);
+            }
+            if args.verify {
+                report.add(day, part, &answer, answer_store.check(day, part, &answer));
+            }
+            if args.record {
+                answer_store.set(day, part, &answer);
+            }
+        }
+    }
+
+    if args.verify {
+        println!();
+        println!("{report}");
+        if !report.is_success() {
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+
+    if args.record {
+        if let Err(error) = answer_store.save(&answers_path) {
+            eprintln!("Failed to write {}: {error}", answers_path.display());
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+
+    if records_benchmarks {
+        if let Err(error) = history.save(&benchmarks_path) {
+            eprintln!("Failed to write {}: {error}", benchmarks_path.display());
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+
+    exit_code
+}
+
+/// All registered solutions, ordered by day.
+fn solutions() -> Vec<Box<dyn DynSolution>> {
+    vec![
+        Box::new(day01::Day01),
+        Box::new(day02::Day02),
+        Box::new(day03::Day03),
+        Box::new(day04::Day04),
+        Box::new(day05::Day05),
+        Box::new(day06::Day06),
+        Box::new(day07::Day07),
+        Box::new(day08::Day08),
+        Box::new(day09::Day09),
+        Box::new(day10::Day10),
+        Box::new(day11::Day11),
+        Box::new(day12::Day12),
+    ]
+}
+
+fn find_solution(solutions: &[Box<dyn DynSolution>], day: u8) -> Option<&dyn DynSolution> {
+    solutions
+        .iter()
+        .find(|solution| solution.day() == day)
+        .map(|solution| solution.as_ref())
+}
+
+fn read_input(args: &Args, day: u8) -> Result<InputReader, InputError> {
+    match &args.input_file {
+        Some(path) if path == Path::new("-") => InputReader::from_stdin(),
+        Some(path) => InputReader::from_file(path),
+        None => InputReader::from_dir(&resolve_input_dir(args.input_dir.as_deref()), day),
+    }
+}
+
+/// The requested part, or both parts when no part is given.
+fn selected_parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+/// Parses the input `nr_runs` times, returning the first parsed input and the timing statistics.
+fn parse_input<'a>(
+    solution: &'a dyn DynSolution,
+    input: &str,
+    nr_runs: usize,
+) -> (ParsedSolution<'a>, TimingStats) {
+    let (parsed, duration) = time(|| solution.parse_input(input));
+
+    let mut samples = vec![duration];
+    for _ in 1..nr_runs {
+        samples.push(time(|| solution.parse_input(input)).1);
+    }
+
+    (parsed, TimingStats::from_samples(&samples).unwrap())
+}
+
+/// Solves a part `nr_runs` times, returning the answer of the first run and the timing statistics.
+fn run_part(parsed: &ParsedSolution, part: u8, nr_runs: usize) -> Option<(String, TimingStats)> {
+    let (answer, duration) = time(|| parsed(part));
+    let answer = answer?;
+
+    let mut samples = vec![duration];
+    for _ in 1..nr_runs {
+        samples.push(time(|| parsed(part)).1);
+    }
+
+    Some((answer, TimingStats::from_samples(&samples)?))
+}
+
+/// Describes the timing, including the change compared to the baseline of a previous benchmark.
+fn describe_timing(stats: &TimingStats, baseline: Option<&TimingStats>) -> String {
+    match baseline {
+        Some(baseline) => {
+            let marker = if stats.is_regression(baseline) {
+                ", REGRESSION"
+            } else {
+                ""
+            };
+            format!(
+                "{stats}, {:+.1}% vs baseline{marker}",
+                stats.relative_change(baseline) * 100.0
+            )
+        }
+        None => stats.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_solutions_are_ordered_by_day() {
+        // Act
+        let days: Vec<u8> = solutions().iter().map(|solution| solution.day()).collect();
+
+        // Assert
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_find_solution() {
+        // Arrange
+        let solutions = solutions();
+
+        // Act
+        let actual = find_solution(&solutions, 7);
+
+        // Assert
+        assert_eq!(actual.map(|solution| solution.day()), Some(7));
+    }
+
+    #[test]
+    fn test_run_part() {
+        // Act
+        let (parsed, parse_stats) = parse_input(&day01::Day01, "L50\n", 3);
+        let actual = run_part(&parsed, 1, 3);
+
+        // Assert
+        let (answer, stats) = actual.unwrap();
+        assert_eq!(answer, "1");
+        assert_eq!(stats.runs, 3);
+        assert_eq!(parse_stats.runs, 3);
+    }
+
+    #[test]
+    fn test_describe_timing_with_regression() {
+        // Arrange
+        let baseline = TimingStats {
+            min: Duration::from_millis(10),
+            median: Duration::from_millis(10),
+            runs: 1,
+        };
+        let stats = TimingStats {
+            min: Duration::from_millis(12),
+            median: Duration::from_millis(15),
+            runs: 3,
+        };
+
+        // Act
+        let actual = describe_timing(&stats, Some(&baseline));
+
+        // Assert
+        let expected = "min 12.00 ms, median 15.00 ms over 3 runs, +50.0% vs baseline, REGRESSION";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_find_solution_unknown_day() {
+        // Arrange
+        let solutions = solutions();
+
+        // Act
+        let actual = find_solution(&solutions, 25);
+
+        // Assert
+        assert!(actual.is_none());
+    }
+}
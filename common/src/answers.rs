@@ -0,0 +1,318 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Name of the answer store file, kept next to the input files since answers depend on the input.
+pub const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+/// The recorded correct answers per day and part, stored in a small TOML subset:
+///
+/// ```toml
+/// [day05]
+/// part1 = "3"
+/// part2 = "14"
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct AnswerStore {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+    /// There is no recorded answer to compare with.
+    Unknown,
+}
+
+impl AnswerStore {
+    pub fn new() -> Self {
+        AnswerStore {
+            answers: BTreeMap::new(),
+        }
+    }
+
+    /// Parses the store from text, returning a message with the offending line number on errors.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut store = AnswerStore::new();
+        let mut current_day: Option<u8> = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line_nr = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let day = parse_day_section(section)
+                    .ok_or(format!("line {line_nr}: invalid section '{line}'"))?;
+                current_day = Some(day);
+                continue;
+            }
+
+            let day = current_day.ok_or(format!(
+                "line {line_nr}: answer outside of a [dayNN] section"
+            ))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("line {line_nr}: expected 'partN = \"answer\"'"))?;
+            let part = parse_part_key(key.trim())
+                .ok_or(format!("line {line_nr}: invalid key '{}'", key.trim()))?;
+            let value = value.trim();
+            let answer = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+            store.set(day, part, answer);
+        }
+
+        Ok(store)
+    }
+
+    /// Loads the store from `path`. A missing file results in an empty store.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|error| format!("{}: {error}", path.display())),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(AnswerStore::new()),
+            Err(error) => Err(format!("{}: {error}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|answer| answer.as_str())
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: &str) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+
+    /// Compares a computed answer with the recorded answer.
+    pub fn check(&self, day: u8, part: u8, actual: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == actual => Verdict::Correct,
+            Some(expected) => Verdict::Incorrect {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for AnswerStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut current_day: Option<u8> = None;
+        for (&(day, part), answer) in &self.answers {
+            if current_day != Some(day) {
+                if current_day.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[day{:02}]", day)?;
+                current_day = Some(day);
+            }
+            writeln!(f, "part{part} = \"{answer}\"")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_day_section(section: &str) -> Option<u8> {
+    section.strip_prefix("day")?.parse().ok()
+}
+
+fn parse_part_key(key: &str) -> Option<u8> {
+    match key {
+        "part1" => Some(1),
+        "part2" => Some(2),
+        _ => None,
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct VerificationResult {
+    pub day: u8,
+    pub part: u8,
+    pub actual: String,
+    pub verdict: Verdict,
+}
+
+/// Collects the verdicts of a verification run and summarises them in a diff-like format.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct VerificationReport {
+    results: Vec<VerificationResult>,
+}
+
+impl VerificationReport {
+    pub fn new() -> Self {
+        VerificationReport { results: vec![] }
+    }
+
+    pub fn add(&mut self, day: u8, part: u8, actual: &str, verdict: Verdict) {
+        self.results.push(VerificationResult {
+            day,
+            part,
+            actual: actual.to_string(),
+            verdict,
+        });
+    }
+
+    pub fn results(&self) -> &[VerificationResult] {
+        &self.results
+    }
+
+    pub fn mismatches(&self) -> impl Iterator<Item = &VerificationResult> {
+        self.results
+            .iter()
+            .filter(|result| matches!(result.verdict, Verdict::Incorrect { .. }))
+    }
+
+    /// True if no computed answer differs from its recorded answer.
+    pub fn is_success(&self) -> bool {
+        self.mismatches().next().is_none()
+    }
+
+    fn count(&self, predicate: impl Fn(&Verdict) -> bool) -> usize {
+        self.results
+            .iter()
+            .filter(|result| predicate(&result.verdict))
+            .count()
+    }
+}
+
+impl fmt::Display for VerificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for result in &self.results {
+            let label = format!("Day {:02} part {}", result.day, result.part);
+            match &result.verdict {
+                Verdict::Correct => writeln!(f, "  {label}: ok")?,
+                Verdict::Unknown => {
+                    writeln!(f, "? {label}: no recorded answer, got {}", result.actual)?
+                }
+                Verdict::Incorrect { expected } => {
+                    writeln!(f, "✗ {label}: mismatch")?;
+                    writeln!(f, "    - {expected}")?;
+                    writeln!(f, "    + {}", result.actual)?;
+                }
+            }
+        }
+
+        write!(
+            f,
+            "{} correct, {} mismatched, {} unknown",
+            self.count(|verdict| *verdict == Verdict::Correct),
+            self.count(|verdict| matches!(verdict, Verdict::Incorrect { .. })),
+            self.count(|verdict| *verdict == Verdict::Unknown),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static ANSWERS: &str = r#"# Answers for my input
+[day05]
+part1 = "3"
+part2 = 14
+
+[day12]
+part1 = "abc"
+"#;
+
+    #[test]
+    fn test_parse() {
+        // Act
+        let actual = AnswerStore::parse(ANSWERS).unwrap();
+
+        // Assert
+        assert_eq!(actual.get(5, 1), Some("3"));
+        assert_eq!(actual.get(5, 2), Some("14"));
+        assert_eq!(actual.get(12, 1), Some("abc"));
+        assert_eq!(actual.get(12, 2), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        // Act
+        let actual = AnswerStore::parse("part1 = \"3\"");
+
+        // Assert
+        assert_eq!(
+            actual,
+            Err("line 1: answer outside of a [dayNN] section".to_string())
+        );
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        // Arrange
+        let mut store = AnswerStore::new();
+        store.set(12, 1, "abc");
+        store.set(5, 2, "14");
+        store.set(5, 1, "3");
+
+        // Act
+        let text = store.to_string();
+
+        // Assert
+        assert_eq!(
+            text,
+            "[day05]\npart1 = \"3\"\npart2 = \"14\"\n\n[day12]\npart1 = \"abc\"\n"
+        );
+        assert_eq!(AnswerStore::parse(&text).unwrap(), store);
+    }
+
+    #[test]
+    fn test_check() {
+        // Arrange
+        let store = AnswerStore::parse(ANSWERS).unwrap();
+
+        // Act & Assert
+        assert_eq!(store.check(5, 1, "3"), Verdict::Correct);
+        assert_eq!(
+            store.check(5, 2, "15"),
+            Verdict::Incorrect {
+                expected: "14".to_string()
+            }
+        );
+        assert_eq!(store.check(6, 1, "1"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_report() {
+        // Arrange
+        let mut report = VerificationReport::new();
+        report.add(5, 1, "3", Verdict::Correct);
+        report.add(
+            5,
+            2,
+            "15",
+            Verdict::Incorrect {
+                expected: "14".to_string(),
+            },
+        );
+        report.add(6, 1, "1", Verdict::Unknown);
+
+        // Act
+        let actual = report.to_string();
+
+        // Assert
+        let expected = "  Day 05 part 1: ok
+✗ Day 05 part 2: mismatch
+    - 14
+    + 15
+? Day 06 part 1: no recorded answer, got 1
+1 correct, 1 mismatched, 1 unknown";
+        assert_eq!(actual, expected);
+        assert!(!report.is_success());
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Name of the benchmark history file, kept next to the input files since timings depend on the input.
+pub const BENCHMARK_FILE_NAME: &str = "benchmarks.txt";
+
+/// A median that is this much slower than the baseline is reported as a regression.
+pub const REGRESSION_THRESHOLD: f64 = 0.10;
+
+/// Runs `f` and returns its result together with the time it took.
+pub fn time<R>(f: impl FnOnce() -> R) -> (R, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Formats a duration with a unit that keeps the number readable, e.g. `12.3 µs` or `1.50 s`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1_000.0 {
+        format!("{nanos:.0} ns")
+    } else if nanos < 1_000_000.0 {
+        format!("{:.1} µs", nanos / 1_000.0)
+    } else if nanos < 1_000_000_000.0 {
+        format!("{:.2} ms", nanos / 1_000_000.0)
+    } else {
+        format!("{:.2} s", nanos / 1_000_000_000.0)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Phase {
+    /// Parsing the input, which both parts share.
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub fn for_part(part: u8) -> Option<Phase> {
+        match part {
+            1 => Some(Phase::Part1),
+            2 => Some(Phase::Part2),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Phase> {
+        match name {
+            "parse" => Some(Phase::Parse),
+            "part1" => Some(Phase::Part1),
+            "part2" => Some(Phase::Part2),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TimingStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub runs: usize,
+}
+
+impl TimingStats {
+    /// Returns None if there are no samples. For an even number of samples the lower median is used.
+    pub fn from_samples(samples: &[Duration]) -> Option<TimingStats> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        Some(TimingStats {
+            min: *sorted.first()?,
+            median: sorted[(sorted.len() - 1) / 2],
+            runs: sorted.len(),
+        })
+    }
+
+    /// The relative change of the median compared to `baseline`, e.g. 0.25 when 25% slower.
+    pub fn relative_change(&self, baseline: &TimingStats) -> f64 {
+        let baseline_nanos = baseline.median.as_nanos().max(1) as f64;
+        (self.median.as_nanos() as f64 - baseline_nanos) / baseline_nanos
+    }
+
+    pub fn is_regression(&self, baseline: &TimingStats) -> bool {
+        self.relative_change(baseline) > REGRESSION_THRESHOLD
+    }
+}
+
+impl fmt::Display for TimingStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.runs == 1 {
+            write!(f, "{}", format_duration(self.median))
+        } else {
+            write!(
+                f,
+                "min {}, median {} over {} runs",
+                format_duration(self.min),
+                format_duration(self.median),
+                self.runs
+            )
+        }
+    }
+}
+
+/// The timings of earlier benchmark runs, stored one line per day and phase:
+/// `day05 part1 <min ns> <median ns> <runs>`.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct BenchmarkHistory {
+    timings: BTreeMap<(u8, Phase), TimingStats>,
+}
+
+impl BenchmarkHistory {
+    pub fn new() -> Self {
+        BenchmarkHistory {
+            timings: BTreeMap::new(),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut history = BenchmarkHistory::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (day, phase, stats) = parse_history_line(line)
+                .ok_or(format!("line {}: invalid timing '{line}'", index + 1))?;
+            history.set(day, phase, stats);
+        }
+
+        Ok(history)
+    }
+
+    /// Loads the history from `path`. A missing file results in an empty history.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|error| format!("{}: {error}", path.display())),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(BenchmarkHistory::new()),
+            Err(error) => Err(format!("{}: {error}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, phase: Phase) -> Option<&TimingStats> {
+        self.timings.get(&(day, phase))
+    }
+
+    pub fn set(&mut self, day: u8, phase: Phase, stats: TimingStats) {
+        self.timings.insert((day, phase), stats);
+    }
+}
+
+impl fmt::Display for BenchmarkHistory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day phase min_ns median_ns runs")?;
+        for (&(day, phase), stats) in &self.timings {
+            writeln!(
+                f,
+                "day{:02} {} {} {} {}",
+                day,
+                phase.name(),
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.runs
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_history_line(line: &str) -> Option<(u8, Phase, TimingStats)> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    if parts.len() != 5 {
+        return None;
+    }
+
+    let day: u8 = parts[0].strip_prefix("day")?.parse().ok()?;
+    let phase = Phase::from_name(parts[1])?;
+    let stats = TimingStats {
+        min: Duration::from_nanos(parts[2].parse().ok()?),
+        median: Duration::from_nanos(parts[3].parse().ok()?),
+        runs: parts[4].parse().ok()?,
+    };
+    Some((day, phase, stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn stats(min_ms: u64, median_ms: u64, runs: usize) -> TimingStats {
+        TimingStats {
+            min: Duration::from_millis(min_ms),
+            median: Duration::from_millis(median_ms),
+            runs,
+        }
+    }
+
+    #[test]
+    fn test_from_samples() {
+        // Arrange
+        let samples: Vec<Duration> = [5, 1, 4, 2, 3].map(Duration::from_millis).to_vec();
+
+        // Act
+        let actual = TimingStats::from_samples(&samples);
+
+        // Assert
+        assert_eq!(actual, Some(stats(1, 3, 5)));
+    }
+
+    #[test]
+    fn test_from_samples_empty() {
+        // Act
+        let actual = TimingStats::from_samples(&[]);
+
+        // Assert
+        assert_eq!(actual, None);
+    }
+
+    #[rstest]
+    #[case(100, 111, true)]
+    #[case(100, 110, false)]
+    #[case(100, 50, false)]
+    fn test_is_regression(
+        #[case] baseline_ms: u64,
+        #[case] current_ms: u64,
+        #[case] expected: bool,
+    ) {
+        // Arrange
+        let baseline = stats(baseline_ms, baseline_ms, 1);
+        let current = stats(current_ms, current_ms, 1);
+
+        // Act
+        let actual = current.is_regression(&baseline);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(Duration::from_nanos(999), "999 ns")]
+    #[case(Duration::from_nanos(12_345), "12.3 µs")]
+    #[case(Duration::from_micros(1_500), "1.50 ms")]
+    #[case(Duration::from_millis(2_500), "2.50 s")]
+    fn test_format_duration(#[case] duration: Duration, #[case] expected: &str) {
+        // Act
+        let actual = format_duration(duration);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_history_round_trips() {
+        // Arrange
+        let mut history = BenchmarkHistory::new();
+        history.set(8, Phase::Part2, stats(10, 12, 5));
+        history.set(8, Phase::Parse, stats(1, 1, 1));
+
+        // Act
+        let text = history.to_string();
+
+        // Assert
+        assert_eq!(
+            text,
+            "# day phase min_ns median_ns runs\nday08 parse 1000000 1000000 1\nday08 part2 10000000 12000000 5\n"
+        );
+        assert_eq!(BenchmarkHistory::parse(&text).unwrap(), history);
+    }
+
+    #[test]
+    fn test_history_parse_invalid() {
+        // Act
+        let actual = BenchmarkHistory::parse("day08 part3 1 1 1");
+
+        // Assert
+        assert!(actual.is_err());
+    }
+}
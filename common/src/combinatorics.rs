@@ -0,0 +1,469 @@
+use std::ops::Range;
+
+/// An iterator over the `k`-element subsets of `0..n`, as ascending indices in lexicographic order.
+pub struct Combinations {
+    n: usize,
+    current: Vec<usize>,
+    done: bool,
+}
+
+impl Combinations {
+    /// # Examples
+    ///
+    /// ```
+    /// use common::Combinations;
+    ///
+    /// let combinations: Vec<Vec<usize>> = Combinations::new(4, 2).collect();
+    /// assert_eq!(combinations[0], vec![0, 1]);
+    /// assert_eq!(combinations[5], vec![2, 3]);
+    /// assert_eq!(combinations.len(), 6);
+    /// ```
+    pub fn new(n: usize, k: usize) -> Self {
+        Combinations {
+            n,
+            current: (0..k).collect(),
+            done: k > n,
+        }
+    }
+}
+
+impl Iterator for Combinations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.current.clone();
+
+        // Find the rightmost index that can still move right, then pack everything after it.
+        let k = self.current.len();
+        match (0..k).rev().find(|&i| self.current[i] < self.n - k + i) {
+            Some(i) => {
+                self.current[i] += 1;
+                for j in i + 1..k {
+                    self.current[j] = self.current[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+
+        Some(result)
+    }
+}
+
+/// An iterator over all orderings of some items, using Heap's algorithm so every permutation
+/// differs from the previous one by a single swap.
+pub struct Permutations<T> {
+    items: Vec<T>,
+    /// The loop counters of the recursive formulation of Heap's algorithm.
+    counters: Vec<usize>,
+    position: usize,
+    started: bool,
+}
+
+impl<T> Permutations<T>
+where
+    T: Clone,
+{
+    /// # Examples
+    ///
+    /// ```
+    /// use common::Permutations;
+    ///
+    /// let mut permutations = Permutations::new(vec!['a', 'b', 'c']);
+    /// assert_eq!(permutations.next(), Some(vec!['a', 'b', 'c']));
+    /// assert_eq!(permutations.next(), Some(vec!['b', 'a', 'c']));
+    /// assert_eq!(permutations.count(), 4);
+    /// ```
+    pub fn new(items: Vec<T>) -> Self {
+        Permutations {
+            counters: vec![0; items.len()],
+            items,
+            position: 1,
+            started: false,
+        }
+    }
+}
+
+impl<T> Iterator for Permutations<T>
+where
+    T: Clone,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some(self.items.clone());
+        }
+
+        while self.position < self.items.len() {
+            let i = self.position;
+            if self.counters[i] < i {
+                if i.is_multiple_of(2) {
+                    self.items.swap(0, i);
+                } else {
+                    self.items.swap(self.counters[i], i);
+                }
+                self.counters[i] += 1;
+                self.position = 1;
+                return Some(self.items.clone());
+            }
+            self.counters[i] = 0;
+            self.position += 1;
+        }
+
+        None
+    }
+}
+
+/// An iterator over every combination of one value from each range, like nested for loops with
+/// the last range innermost.
+pub struct CartesianProduct {
+    ranges: Vec<Range<i64>>,
+    current: Option<Vec<i64>>,
+}
+
+impl CartesianProduct {
+    /// # Examples
+    ///
+    /// ```
+    /// use common::CartesianProduct;
+    ///
+    /// let product: Vec<Vec<i64>> = CartesianProduct::new([0..2, 5..8]).collect();
+    /// assert_eq!(product[0], vec![0, 5]);
+    /// assert_eq!(product[3], vec![1, 5]);
+    /// assert_eq!(product.len(), 6);
+    /// ```
+    pub fn new<I>(ranges: I) -> Self
+    where
+        I: IntoIterator<Item = Range<i64>>,
+    {
+        let ranges: Vec<Range<i64>> = ranges.into_iter().collect();
+        let current = ranges
+            .iter()
+            .all(|range| !range.is_empty())
+            .then(|| ranges.iter().map(|range| range.start).collect());
+        CartesianProduct { ranges, current }
+    }
+}
+
+impl Iterator for CartesianProduct {
+    type Item = Vec<i64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.as_mut()?;
+        let result = current.clone();
+
+        // Count like an odometer, carrying into the previous range when one runs out.
+        let mut exhausted = true;
+        for (value, range) in current.iter_mut().zip(&self.ranges).rev() {
+            *value += 1;
+            if *value < range.end {
+                exhausted = false;
+                break;
+            }
+            *value = range.start;
+        }
+        if exhausted {
+            self.current = None;
+        }
+
+        Some(result)
+    }
+}
+
+/// An iterator over the subsets of `0..n` as bitmasks, first by increasing number of elements and
+/// then by increasing value. Useful to find the smallest subset satisfying some condition.
+pub struct SubsetsByPopcount {
+    n: u32,
+    current: Option<u128>,
+}
+
+impl SubsetsByPopcount {
+    /// Panics if `n` is larger than 64.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common::SubsetsByPopcount;
+    ///
+    /// let subsets: Vec<u64> = SubsetsByPopcount::new(3).collect();
+    /// assert_eq!(subsets, vec![0b000, 0b001, 0b010, 0b100, 0b011, 0b101, 0b110, 0b111]);
+    /// ```
+    pub fn new(n: u32) -> Self {
+        assert!(
+            n <= 64,
+            "Subsets of at most 64 elements fit in a u64, got {n}"
+        );
+        SubsetsByPopcount {
+            n,
+            current: Some(0),
+        }
+    }
+}
+
+impl Iterator for SubsetsByPopcount {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current?;
+        let popcount = current.count_ones();
+
+        let next = if current == 0 {
+            1
+        } else {
+            // Gosper's hack: the next larger number with the same number of bits set.
+            let lowest = current & current.wrapping_neg();
+            let ripple = current + lowest;
+            ripple | (((current ^ ripple) >> 2) / lowest)
+        };
+        self.current = if next < 1 << self.n {
+            Some(next)
+        } else if popcount < self.n {
+            Some((1 << (popcount + 1)) - 1)
+        } else {
+            None
+        };
+
+        Some(current as u64)
+    }
+}
+
+/// An iterator over the ways to write `total` as an ordered sum of `parts` non-negative numbers,
+/// in lexicographic order. Equivalently, the ways to put `total` identical items into `parts` bins.
+pub struct Compositions {
+    current: Option<Vec<u64>>,
+}
+
+impl Compositions {
+    /// # Examples
+    ///
+    /// ```
+    /// use common::Compositions;
+    ///
+    /// let compositions: Vec<Vec<u64>> = Compositions::new(2, 2).collect();
+    /// assert_eq!(compositions, vec![vec![0, 2], vec![1, 1], vec![2, 0]]);
+    /// ```
+    pub fn new(total: u64, parts: usize) -> Self {
+        let current = match parts {
+            0 => (total == 0).then(Vec::new),
+            _ => {
+                let mut first = vec![0; parts];
+                first[parts - 1] = total;
+                Some(first)
+            }
+        };
+        Compositions { current }
+    }
+}
+
+impl Iterator for Compositions {
+    type Item = Vec<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.as_mut()?;
+        let result = current.clone();
+
+        // Move one unit from the tail into the rightmost part that has a non-empty tail, and put
+        // the rest of the tail in the last part.
+        let last = current.len().saturating_sub(1);
+        let mut tail = 0;
+        let mut advanced = false;
+        for i in (0..last).rev() {
+            tail += current[i + 1];
+            if tail > 0 {
+                current[i] += 1;
+                current[i + 1..].fill(0);
+                current[last] = tail - 1;
+                advanced = true;
+                break;
+            }
+        }
+        if !advanced {
+            self.current = None;
+        }
+
+        Some(result)
+    }
+}
+
+/// An iterator walking all subsets of `0..n` as bitmasks in Gray code order, where every subset
+/// differs from the previous one by a single element. Yields each subset with the element that was
+/// added or removed to reach it, starting with the empty set and None.
+pub struct GrayCode {
+    n: u32,
+    step: u128,
+}
+
+impl GrayCode {
+    /// Panics if `n` is larger than 64.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common::GrayCode;
+    ///
+    /// let walk: Vec<(u64, Option<usize>)> = GrayCode::new(2).collect();
+    /// assert_eq!(walk, vec![(0b00, None), (0b01, Some(0)), (0b11, Some(1)), (0b10, Some(0))]);
+    /// ```
+    pub fn new(n: u32) -> Self {
+        assert!(
+            n <= 64,
+            "Subsets of at most 64 elements fit in a u64, got {n}"
+        );
+        GrayCode { n, step: 0 }
+    }
+}
+
+impl Iterator for GrayCode {
+    type Item = (u64, Option<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step >= 1 << self.n {
+            return None;
+        }
+        let step = self.step;
+        self.step += 1;
+
+        let mask = (step ^ (step >> 1)) as u64;
+        let flipped = (step > 0).then(|| step.trailing_zeros() as usize);
+        Some((mask, flipped))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(5, 0, 1)]
+    #[case(5, 1, 5)]
+    #[case(5, 3, 10)]
+    #[case(5, 5, 1)]
+    #[case(3, 4, 0)]
+    #[case(0, 0, 1)]
+    fn test_combinations_count(#[case] n: usize, #[case] k: usize, #[case] expected: usize) {
+        // Act
+        let actual: Vec<Vec<usize>> = Combinations::new(n, k).collect();
+
+        // Assert
+        assert_eq!(actual.len(), expected);
+        assert!(actual.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(actual
+            .iter()
+            .all(|c| c.len() == k && c.iter().all(|&i| i < n)));
+    }
+
+    #[test]
+    fn test_combinations_order() {
+        // Act
+        let actual: Vec<Vec<usize>> = Combinations::new(4, 3).collect();
+
+        // Assert
+        let expected = vec![vec![0, 1, 2], vec![0, 1, 3], vec![0, 2, 3], vec![1, 2, 3]];
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(0, 1)]
+    #[case(1, 1)]
+    #[case(4, 24)]
+    #[case(6, 720)]
+    fn test_permutations_are_distinct_single_swaps(#[case] n: usize, #[case] expected: usize) {
+        // Act
+        let actual: Vec<Vec<usize>> = Permutations::new((0..n).collect()).collect();
+
+        // Assert
+        let mut distinct = actual.clone();
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(distinct.len(), expected);
+        assert_eq!(actual.len(), expected);
+        for pair in actual.windows(2) {
+            let differences = pair[0].iter().zip(&pair[1]).filter(|(a, b)| a != b);
+            assert_eq!(differences.count(), 2);
+        }
+    }
+
+    #[rstest]
+    #[case(vec![0..2, -1..1, 3..4], 4)]
+    #[case(vec![0..3, 0..0], 0)]
+    #[case(vec![], 1)]
+    fn test_cartesian_product(#[case] ranges: Vec<Range<i64>>, #[case] expected: usize) {
+        // Act
+        let actual: Vec<Vec<i64>> = CartesianProduct::new(ranges.clone()).collect();
+
+        // Assert
+        assert_eq!(actual.len(), expected);
+        assert!(actual.windows(2).all(|pair| pair[0] < pair[1]));
+        for tuple in actual {
+            assert!(tuple.iter().zip(&ranges).all(|(v, r)| r.contains(v)));
+        }
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    #[case(5)]
+    fn test_subsets_by_popcount(#[case] n: u32) {
+        // Act
+        let actual: Vec<u64> = SubsetsByPopcount::new(n).collect();
+
+        // Assert
+        let mut expected: Vec<u64> = (0..1 << n).collect();
+        expected.sort_by_key(|&mask| (mask.count_ones(), mask));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_subsets_by_popcount_of_64_elements() {
+        // Act
+        let mut actual = SubsetsByPopcount::new(64);
+
+        // Assert
+        assert_eq!(actual.nth(64), Some(1 << 63));
+        assert_eq!(actual.next(), Some(0b11));
+    }
+
+    #[rstest]
+    #[case(3, 3, 10)]
+    #[case(4, 1, 1)]
+    #[case(0, 3, 1)]
+    #[case(0, 0, 1)]
+    #[case(2, 0, 0)]
+    fn test_compositions(#[case] total: u64, #[case] parts: usize, #[case] expected: usize) {
+        // Act
+        let actual: Vec<Vec<u64>> = Compositions::new(total, parts).collect();
+
+        // Assert
+        assert_eq!(actual.len(), expected);
+        assert!(actual.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(actual
+            .iter()
+            .all(|c| c.len() == parts && c.iter().sum::<u64>() == total));
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    #[case(4)]
+    fn test_gray_code(#[case] n: u32) {
+        // Act
+        let actual: Vec<(u64, Option<usize>)> = GrayCode::new(n).collect();
+
+        // Assert
+        let mut masks: Vec<u64> = actual.iter().map(|&(mask, _)| mask).collect();
+        assert_eq!(actual[0], (0, None));
+        for pair in actual.windows(2) {
+            let (previous, _) = pair[0];
+            let (mask, flipped) = pair[1];
+            assert_eq!(previous ^ mask, 1 << flipped.unwrap());
+        }
+        masks.sort();
+        assert_eq!(masks, (0..1 << n).collect::<Vec<u64>>());
+    }
+}
@@ -0,0 +1,94 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Vector math shared by [`crate::Point`] and [`crate::Point3d`], so 2D and 3D code can be written once.
+/// Implementors only list their components; everything else follows from those.
+pub trait Coordinate:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<i64, Output = Self> + Neg<Output = Self>
+{
+    /// The components in axis order, e.g. x, y, z.
+    fn components(&self) -> impl Iterator<Item = i64>;
+
+    /// Builds a coordinate from its components in axis order.
+    fn from_components(components: impl Iterator<Item = i64>) -> Self;
+
+    fn dot(&self, other: &Self) -> i64 {
+        self.components()
+            .zip(other.components())
+            .map(|(a, b)| a * b)
+            .sum()
+    }
+
+    /// The squared euclidean distance, which unlike the distance itself is exact.
+    fn squared_distance(&self, other: &Self) -> i64 {
+        let delta = *other - *self;
+        delta.dot(&delta)
+    }
+
+    fn manhattan_distance(&self, other: &Self) -> u64 {
+        (*other - *self).components().map(i64::unsigned_abs).sum()
+    }
+
+    /// The largest distance along a single axis, i.e. the number of king moves between both points.
+    fn chebyshev_distance(&self, other: &Self) -> u64 {
+        (*other - *self)
+            .components()
+            .map(i64::unsigned_abs)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Every component replaced by -1, 0 or 1, which turns an offset into a single step towards it.
+    fn signum(&self) -> Self {
+        Self::from_components(self.components().map(i64::signum))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{Point, Point3d};
+
+    #[rstest]
+    #[case(Point::new(1, 2), Point::new(4, -2), 25, 7, 4)]
+    #[case(Point::new(0, 0), Point::new(0, 0), 0, 0, 0)]
+    fn test_distances_2d(
+        #[case] a: Point,
+        #[case] b: Point,
+        #[case] squared: i64,
+        #[case] manhattan: u64,
+        #[case] chebyshev: u64,
+    ) {
+        // Act & Assert
+        assert_eq!(a.squared_distance(&b), squared);
+        assert_eq!(a.manhattan_distance(&b), manhattan);
+        assert_eq!(a.chebyshev_distance(&b), chebyshev);
+    }
+
+    #[test]
+    fn test_distances_3d() {
+        // Arrange
+        let a = Point3d::new(1, 2, 3);
+        let b = Point3d::new(2, 4, 6);
+
+        // Act & Assert
+        assert_eq!(a.squared_distance(&b), 14);
+        assert_eq!(a.manhattan_distance(&b), 6);
+        assert_eq!(a.chebyshev_distance(&b), 3);
+    }
+
+    #[test]
+    fn test_dot() {
+        // Act & Assert
+        assert_eq!(Point::new(1, 2).dot(&Point::new(3, -4)), -5);
+        assert_eq!(Point3d::new(1, 2, 3).dot(&Point3d::new(4, 5, 6)), 32);
+    }
+
+    #[test]
+    fn test_signum() {
+        // Act & Assert
+        assert_eq!(Point::new(-7, 0).signum(), Point::new(-1, 0));
+        assert_eq!(Point3d::new(3, -2, 0).signum(), Point3d::new(1, -1, 0));
+    }
+}
@@ -0,0 +1,199 @@
+use crate::Point3d;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis {
+    fn of(&self, p: &Point3d) -> i64 {
+        match self {
+            Axis::X => p.x,
+            Axis::Y => p.y,
+            Axis::Z => p.z,
+        }
+    }
+
+    fn with(&self, p: &Point3d, value: i64) -> Point3d {
+        match self {
+            Axis::X => Point3d::new(value, p.y, p.z),
+            Axis::Y => Point3d::new(p.x, value, p.z),
+            Axis::Z => Point3d::new(p.x, p.y, value),
+        }
+    }
+}
+
+/// An axis-aligned box in 3D, the counterpart of [`crate::Rect`]. Both corners are inclusive.
+#[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+pub struct Cuboid {
+    pub min: Point3d,
+    pub max: Point3d,
+}
+
+impl Cuboid {
+    pub fn new(p1: &Point3d, p2: &Point3d) -> Cuboid {
+        Cuboid {
+            min: Point3d::new(p1.x.min(p2.x), p1.y.min(p2.y), p1.z.min(p2.z)),
+            max: Point3d::new(p1.x.max(p2.x), p1.y.max(p2.y), p1.z.max(p2.z)),
+        }
+    }
+
+    /// The smallest cuboid containing all points, or None if there are no points.
+    pub fn bounding<I>(points: I) -> Option<Cuboid>
+    where
+        I: IntoIterator<Item = Point3d>,
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Cuboid::new(&first, &first), |cuboid, p| {
+            Cuboid::new(
+                &Point3d::new(
+                    cuboid.min.x.min(p.x),
+                    cuboid.min.y.min(p.y),
+                    cuboid.min.z.min(p.z),
+                ),
+                &Point3d::new(
+                    cuboid.max.x.max(p.x),
+                    cuboid.max.y.max(p.y),
+                    cuboid.max.z.max(p.z),
+                ),
+            )
+        }))
+    }
+
+    pub fn contains(&self, p: &Point3d) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x)
+            && (self.min.y..=self.max.y).contains(&p.y)
+            && (self.min.z..=self.max.z).contains(&p.z)
+    }
+
+    /// The number of points inside the cuboid.
+    pub fn volume(&self) -> u64 {
+        let size = self.max - self.min;
+        (size.x as u64 + 1) * (size.y as u64 + 1) * (size.z as u64 + 1)
+    }
+
+    /// The cuboid covered by both cuboids, or None if they don't overlap.
+    pub fn intersect(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = Point3d::new(
+            self.min.x.max(other.min.x),
+            self.min.y.max(other.min.y),
+            self.min.z.max(other.min.z),
+        );
+        let max = Point3d::new(
+            self.max.x.min(other.max.x),
+            self.max.y.min(other.max.y),
+            self.max.z.min(other.max.z),
+        );
+        if min.x > max.x || min.y > max.y || min.z > max.z {
+            return None;
+        }
+        Some(Cuboid { min, max })
+    }
+
+    /// Splits the cuboid into the part below `at` and the part from `at` onwards along `axis`.
+    /// A part is None when the cuboid lies entirely on the other side.
+    pub fn split(&self, axis: Axis, at: i64) -> (Option<Cuboid>, Option<Cuboid>) {
+        let below = (axis.of(&self.min) < at).then(|| Cuboid {
+            min: self.min,
+            max: axis.with(&self.max, axis.of(&self.max).min(at - 1)),
+        });
+        let above = (axis.of(&self.max) >= at).then(|| Cuboid {
+            min: axis.with(&self.min, axis.of(&self.min).max(at)),
+            max: self.max,
+        });
+        (below, above)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn cuboid(min: (i64, i64, i64), max: (i64, i64, i64)) -> Cuboid {
+        Cuboid::new(
+            &Point3d::new(min.0, min.1, min.2),
+            &Point3d::new(max.0, max.1, max.2),
+        )
+    }
+
+    #[test]
+    fn test_new_normalizes_corners() {
+        // Act
+        let actual = Cuboid::new(&Point3d::new(3, 0, 5), &Point3d::new(1, 2, 4));
+
+        // Assert
+        assert_eq!(actual, cuboid((1, 0, 4), (3, 2, 5)));
+        assert_eq!(actual.volume(), 18);
+    }
+
+    #[rstest]
+    #[case(Point3d::new(0, 0, 0), true)]
+    #[case(Point3d::new(2, 2, 2), true)]
+    #[case(Point3d::new(3, 0, 0), false)]
+    #[case(Point3d::new(0, 0, -1), false)]
+    fn test_contains(#[case] point: Point3d, #[case] expected: bool) {
+        // Arrange
+        let cube = cuboid((0, 0, 0), (2, 2, 2));
+
+        // Act
+        let actual = cube.contains(&point);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(cuboid((0, 0, 0), (2, 2, 2)), cuboid((1, 1, 1), (3, 3, 3)), Some(cuboid((1, 1, 1), (2, 2, 2))))]
+    #[case(cuboid((0, 0, 0), (2, 2, 2)), cuboid((2, 0, 0), (4, 0, 0)), Some(cuboid((2, 0, 0), (2, 0, 0))))]
+    #[case(cuboid((0, 0, 0), (2, 2, 2)), cuboid((0, 0, 3), (2, 2, 4)), None)]
+    fn test_intersect(#[case] a: Cuboid, #[case] b: Cuboid, #[case] expected: Option<Cuboid>) {
+        // Act
+        let actual = a.intersect(&b);
+
+        // Assert
+        assert_eq!(actual, expected);
+        assert_eq!(b.intersect(&a), expected);
+    }
+
+    #[rstest]
+    #[case(Axis::X, 2, Some(cuboid((0, 0, 0), (1, 3, 3))), Some(cuboid((2, 0, 0), (3, 3, 3))))]
+    #[case(Axis::Z, 0, None, Some(cuboid((0, 0, 0), (3, 3, 3))))]
+    #[case(Axis::Y, 4, Some(cuboid((0, 0, 0), (3, 3, 3))), None)]
+    fn test_split(
+        #[case] axis: Axis,
+        #[case] at: i64,
+        #[case] below: Option<Cuboid>,
+        #[case] above: Option<Cuboid>,
+    ) {
+        // Arrange
+        let cube = cuboid((0, 0, 0), (3, 3, 3));
+
+        // Act
+        let actual = cube.split(axis, at);
+
+        // Assert
+        assert_eq!(actual, (below, above));
+    }
+
+    #[test]
+    fn test_bounding() {
+        // Arrange
+        let points = [
+            Point3d::new(1, 5, -2),
+            Point3d::new(-3, 0, 4),
+            Point3d::new(2, 2, 2),
+        ];
+
+        // Act
+        let actual = Cuboid::bounding(points);
+
+        // Assert
+        assert_eq!(actual, Some(cuboid((-3, 0, -2), (2, 5, 4))));
+        assert_eq!(Cuboid::bounding([]), None);
+    }
+}
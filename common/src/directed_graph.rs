@@ -1,4 +1,58 @@
 use std::collections::HashMap;
+use std::fmt;
+
+use crate::Graph;
+
+#[derive(Debug, PartialEq, Clone, Eq)]
+pub enum GraphError {
+    UnknownNode(String),
+    /// The graph contains a cycle through these nodes, in order; the last node links back to the first.
+    Cycle(Vec<String>),
+    /// A weighted graph contains a cycle whose weights sum to less than zero.
+    NegativeCycle,
+    /// The number of paths doesn't fit in the requested integer type.
+    TooManyPaths(u128),
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphError::UnknownNode(name) => write!(f, "unknown node '{name}'"),
+            GraphError::Cycle(nodes) => {
+                write!(
+                    f,
+                    "graph contains a cycle: {} -> {}",
+                    nodes.join(" -> "),
+                    nodes[0]
+                )
+            }
+            GraphError::NegativeCycle => write!(f, "graph contains a negative cycle"),
+            GraphError::TooManyPaths(nr_paths) => {
+                write!(f, "{nr_paths} paths don't fit in the result type")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+/// The strongly connected components of a graph, and the acyclic graph between them.
+#[derive(Debug, PartialEq, Clone, Eq)]
+pub struct Condensation {
+    /// The node names of every component, with the components in topological order.
+    pub components: Vec<Vec<String>>,
+    /// A graph with a node per component, named after the first node of that component.
+    /// It has an edge between two components if any of their nodes are connected.
+    pub dag: DirectedGraph,
+    component_indices: HashMap<String, usize>,
+}
+
+impl Condensation {
+    /// The index in `components` of the component containing `name`.
+    pub fn component_of(&self, name: &str) -> Option<usize> {
+        self.component_indices.get(name).copied()
+    }
+}
 
 #[derive(Debug, PartialEq, Clone, Eq, Hash)]
 pub struct GraphNode {
@@ -69,12 +123,143 @@ impl DirectedGraph {
         }
     }
 
-    pub fn count_possible_paths(&self, start_node: &str, end_node: &str) -> i64 {
-        let start_node_index = self.get_node_index(start_node);
-        let end_node_index = self.get_node_index(end_node);
-        let mut visited: HashMap<usize, i64> = HashMap::new();
+    /// Counts the distinct paths from `start_node` to `end_node`. Fails if either node is unknown, or
+    /// if a cycle is reachable from `start_node` since the number of paths could then be infinite.
+    /// Fails with [`GraphError::TooManyPaths`] if the count exceeds `i64`, use
+    /// [`DirectedGraph::count_paths_via`] for the full `u128` count.
+    pub fn count_possible_paths(
+        &self,
+        start_node: &str,
+        end_node: &str,
+    ) -> Result<i64, GraphError> {
+        let nr_paths = self.count_paths_via(start_node, end_node, &[])?;
+        i64::try_from(nr_paths).map_err(|_| GraphError::TooManyPaths(nr_paths))
+    }
+
+    /// Counts the paths from `start_node` to `end_node` that pass through every node in `waypoints`,
+    /// in any order. Fails like [`DirectedGraph::count_possible_paths`], or if a waypoint is unknown.
+    pub fn count_paths_via(
+        &self,
+        start_node: &str,
+        end_node: &str,
+        waypoints: &[&str],
+    ) -> Result<u128, GraphError> {
+        self.count_paths(start_node, end_node, waypoints, &[])
+    }
+
+    /// Counts the paths from `start_node` to `end_node` that pass through none of the `avoided` nodes.
+    pub fn count_paths_avoiding(
+        &self,
+        start_node: &str,
+        end_node: &str,
+        avoided: &[&str],
+    ) -> Result<u128, GraphError> {
+        self.count_paths(start_node, end_node, &[], avoided)
+    }
 
-        self.count_possible_paths_from(start_node_index, end_node_index, &mut visited)
+    /// Converts to a [`Graph`] with the node names as payload and unweighted edges, keeping the
+    /// node indices.
+    pub fn to_graph(&self) -> Graph<String, ()> {
+        let mut graph = Graph::directed();
+        for node in &self.nodes {
+            graph.add_node(node.name.clone());
+        }
+        for (from, node) in self.nodes.iter().enumerate() {
+            for &to in &node.edges {
+                graph.add_edge(from, to, ());
+            }
+        }
+        graph
+    }
+
+    /// Writes the graph in the Graphviz DOT language, with every node labelled by its name.
+    pub fn to_dot(&self) -> String {
+        self.to_graph().to_dot_with(|name| name.clone(), |_| None)
+    }
+
+    /// Adds a node without edges, unless a node with this name already exists.
+    pub fn add_node(&mut self, name: &str) {
+        self.add_node_if_unknown(name);
+    }
+
+    /// The node names ordered so that every edge goes from an earlier to a later node,
+    /// or the nodes of a cycle if there is no such order.
+    pub fn topological_order(&self) -> Result<Vec<&str>, GraphError> {
+        let (postorder, cycle) = self.dfs_postorder(0..self.nodes.len());
+        if let Some(cycle) = cycle {
+            return Err(self.cycle_error(cycle));
+        }
+        Ok(postorder
+            .into_iter()
+            .rev()
+            .map(|index| self.nodes[index].name.as_str())
+            .collect())
+    }
+
+    pub fn is_acyclic(&self) -> bool {
+        self.topological_order().is_ok()
+    }
+
+    /// The groups of nodes that can all reach each other, using Kosaraju's algorithm.
+    /// The components are in topological order: no edge leads to an earlier component.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<&str>> {
+        self.component_indices()
+            .into_iter()
+            .map(|component| {
+                component
+                    .into_iter()
+                    .map(|index| self.nodes[index].name.as_str())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Collapses every strongly connected component into a single node, which leaves a graph
+    /// without cycles.
+    pub fn condensation(&self) -> Condensation {
+        let components = self.component_indices();
+        let mut component_of = vec![0; self.nodes.len()];
+        for (component_index, component) in components.iter().enumerate() {
+            for &node_index in component {
+                component_of[node_index] = component_index;
+            }
+        }
+
+        let names: Vec<&str> = components
+            .iter()
+            .map(|component| self.nodes[component[0]].name.as_str())
+            .collect();
+        let mut dag = DirectedGraph::new();
+        for name in &names {
+            dag.add_node(name);
+        }
+        for (from, node) in self.nodes.iter().enumerate() {
+            for &to in &node.edges {
+                let (from_name, to_name) = (names[component_of[from]], names[component_of[to]]);
+                if component_of[from] != component_of[to] && !dag.has_edge(from_name, to_name) {
+                    dag.add_edge(from_name, to_name);
+                }
+            }
+        }
+
+        Condensation {
+            components: components
+                .iter()
+                .map(|component| {
+                    component
+                        .iter()
+                        .map(|&index| self.nodes[index].name.clone())
+                        .collect()
+                })
+                .collect(),
+            dag,
+            component_indices: self
+                .nodes
+                .iter()
+                .enumerate()
+                .map(|(index, node)| (node.name.clone(), component_of[index]))
+                .collect(),
+        }
     }
 
     fn add_node_if_unknown(&mut self, name: &str) {
@@ -88,34 +273,241 @@ impl DirectedGraph {
         *self.node_map.get(name).unwrap()
     }
 
-    fn count_possible_paths_from(
+    fn try_node_index(&self, name: &str) -> Result<usize, GraphError> {
+        self.node_map
+            .get(name)
+            .copied()
+            .ok_or(GraphError::UnknownNode(name.to_string()))
+    }
+
+    fn cycle_error(&self, cycle: Vec<usize>) -> GraphError {
+        GraphError::Cycle(
+            cycle
+                .into_iter()
+                .map(|index| self.nodes[index].name.clone())
+                .collect(),
+        )
+    }
+
+    /// The nodes reachable from `roots` in depth-first postorder, together with the node indices of
+    /// the first cycle found, if any.
+    fn dfs_postorder<I>(&self, roots: I) -> (Vec<usize>, Option<Vec<usize>>)
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            Unvisited,
+            OnStack,
+            Done,
+        }
+
+        let mut states = vec![State::Unvisited; self.nodes.len()];
+        let mut postorder = Vec::with_capacity(self.nodes.len());
+        let mut cycle = None;
+        for root in roots {
+            if states[root] != State::Unvisited {
+                continue;
+            }
+            states[root] = State::OnStack;
+            // Every entry holds a node and the index of the next edge to follow.
+            let mut stack: Vec<(usize, usize)> = vec![(root, 0)];
+            while let Some(&mut (node, ref mut next_edge)) = stack.last_mut() {
+                if let Some(&next) = self.nodes[node].edges.get(*next_edge) {
+                    *next_edge += 1;
+                    match states[next] {
+                        State::Unvisited => {
+                            states[next] = State::OnStack;
+                            stack.push((next, 0));
+                        }
+                        State::OnStack if cycle.is_none() => {
+                            let cycle_start = stack.iter().position(|&(n, _)| n == next).unwrap();
+                            cycle = Some(stack[cycle_start..].iter().map(|&(n, _)| n).collect());
+                        }
+                        State::OnStack => {}
+                        State::Done => {}
+                    }
+                } else {
+                    stack.pop();
+                    states[node] = State::Done;
+                    postorder.push(node);
+                }
+            }
+        }
+
+        (postorder, cycle)
+    }
+
+    /// Kosaraju: visit the reversed graph in decreasing finishing time of a DFS on the graph itself.
+    fn component_indices(&self) -> Vec<Vec<usize>> {
+        let (postorder, _) = self.dfs_postorder(0..self.nodes.len());
+        let mut reversed_edges: Vec<Vec<usize>> = vec![vec![]; self.nodes.len()];
+        for (from, node) in self.nodes.iter().enumerate() {
+            for &to in &node.edges {
+                reversed_edges[to].push(from);
+            }
+        }
+
+        let mut is_assigned = vec![false; self.nodes.len()];
+        let mut components = vec![];
+        for &root in postorder.iter().rev() {
+            if is_assigned[root] {
+                continue;
+            }
+            is_assigned[root] = true;
+            let mut component = vec![];
+            let mut stack = vec![root];
+            while let Some(node) = stack.pop() {
+                component.push(node);
+                for &previous in &reversed_edges[node] {
+                    if !is_assigned[previous] {
+                        is_assigned[previous] = true;
+                        stack.push(previous);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+
+        components
+    }
+
+    fn count_paths(
         &self,
-        current_node_index: usize,
-        end_node_index: usize,
-        visited: &mut HashMap<usize, i64>,
-    ) -> i64 {
-        if current_node_index == end_node_index {
-            return 1;
+        start_node: &str,
+        end_node: &str,
+        waypoints: &[&str],
+        avoided: &[&str],
+    ) -> Result<u128, GraphError> {
+        let start_node_index = self.try_node_index(start_node)?;
+        let end_node_index = self.try_node_index(end_node)?;
+        if let (_, Some(cycle)) = self.dfs_postorder([start_node_index]) {
+            return Err(self.cycle_error(cycle));
         }
 
-        if visited.contains_key(&current_node_index) {
-            return visited[&current_node_index];
+        // Every distinct waypoint gets a bit, so the memo can tell apart arriving at a node with
+        // different waypoints seen.
+        let mut waypoint_bits: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut nr_waypoints = 0;
+        for waypoint in waypoints {
+            let index = self.try_node_index(waypoint)?;
+            if waypoint_bits[index].is_none() {
+                waypoint_bits[index] = Some(nr_waypoints);
+                nr_waypoints += 1;
+            }
+        }
+        let mut is_avoided = vec![false; self.nodes.len()];
+        for name in avoided {
+            // Unknown nodes can't be on any path, so there is nothing to avoid.
+            if let Some(&index) = self.node_map.get(*name) {
+                is_avoided[index] = true;
+            }
+        }
+
+        let mut all_waypoints = vec![0u64; nr_waypoints.div_ceil(64)];
+        for bit in 0..nr_waypoints {
+            all_waypoints[bit / 64] |= 1 << (bit % 64);
+        }
+        let search = PathSearch {
+            end_node_index,
+            seen_none: vec![0u64; all_waypoints.len()],
+            all_waypoints,
+            waypoint_bits,
+            is_avoided,
+        };
+
+        Ok(self.count_paths_from(start_node_index, &search))
+    }
+
+    /// A depth-first count over (node, seen waypoints) states, memoised per state. The stack is
+    /// explicit, so long chains of nodes don't overflow the call stack.
+    fn count_paths_from(&self, start_node_index: usize, search: &PathSearch) -> u128 {
+        let mut visited: HashMap<(usize, Vec<u64>), u128> = HashMap::new();
+        let mut stack: Vec<PathFrame> = vec![];
+        let mut nr_paths = self.enter_path_node(
+            start_node_index,
+            &search.seen_none,
+            search,
+            &visited,
+            &mut stack,
+        );
+
+        while let Some(frame) = stack.last_mut() {
+            if let Some(nr_paths) = nr_paths.take() {
+                frame.nr_paths += nr_paths;
+            }
+
+            let (node_index, seen_waypoints) = &frame.state;
+            if let Some(&next) = self.nodes[*node_index].edges.get(frame.next_edge) {
+                frame.next_edge += 1;
+                let seen_waypoints = seen_waypoints.clone();
+                nr_paths =
+                    self.enter_path_node(next, &seen_waypoints, search, &visited, &mut stack);
+            } else {
+                let frame = stack.pop().unwrap();
+                visited.insert(frame.state, frame.nr_paths);
+                nr_paths = Some(frame.nr_paths);
+            }
         }
 
-        let mut nr_possible_paths: i64 = 0;
+        nr_paths.unwrap()
+    }
+
+    /// Returns the number of paths from the node right away if it is avoided, the end node or
+    /// already counted. Otherwise pushes it on `stack` to count the paths along its edges.
+    fn enter_path_node(
+        &self,
+        node_index: usize,
+        seen_waypoints: &[u64],
+        search: &PathSearch,
+        visited: &HashMap<(usize, Vec<u64>), u128>,
+        stack: &mut Vec<PathFrame>,
+    ) -> Option<u128> {
+        if search.is_avoided[node_index] {
+            return Some(0);
+        }
 
-        let node = &self.nodes[current_node_index];
-        for new_node_index in node.edges.iter() {
-            nr_possible_paths +=
-                self.count_possible_paths_from(*new_node_index, end_node_index, visited);
+        let mut seen_waypoints = seen_waypoints.to_vec();
+        if let Some(bit) = search.waypoint_bits[node_index] {
+            seen_waypoints[bit / 64] |= 1 << (bit % 64);
+        }
+        if node_index == search.end_node_index {
+            return Some((seen_waypoints == search.all_waypoints) as u128);
         }
 
-        visited.insert(current_node_index, nr_possible_paths);
+        let state = (node_index, seen_waypoints);
+        if let Some(&nr_paths) = visited.get(&state) {
+            return Some(nr_paths);
+        }
+        stack.push(PathFrame {
+            state,
+            next_edge: 0,
+            nr_paths: 0,
+        });
 
-        nr_possible_paths
+        None
     }
 }
 
+/// What a path count is looking for, by node index. Sets of seen waypoints are bitmasks split
+/// over as many words as needed.
+struct PathSearch {
+    end_node_index: usize,
+    seen_none: Vec<u64>,
+    all_waypoints: Vec<u64>,
+    waypoint_bits: Vec<Option<usize>>,
+    is_avoided: Vec<bool>,
+}
+
+/// A state whose paths are being counted: the index of the next edge to follow and the number of
+/// paths found along the edges before it.
+struct PathFrame {
+    state: (usize, Vec<u64>),
+    next_edge: usize,
+    nr_paths: u128,
+}
+
 impl Default for DirectedGraph {
     fn default() -> Self {
         Self::new()
@@ -124,6 +516,8 @@ impl Default for DirectedGraph {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -283,7 +677,7 @@ mod tests {
         sut.add_edge("C", "D");
 
         // Act
-        let actual = sut.count_possible_paths("A", "D");
+        let actual = sut.count_possible_paths("A", "D").unwrap();
 
         // Assert
         assert_eq!(actual, 1);
@@ -299,7 +693,7 @@ mod tests {
         sut.add_edge("C", "D");
 
         // Act
-        let actual = sut.count_possible_paths("A", "D");
+        let actual = sut.count_possible_paths("A", "D").unwrap();
 
         // Assert
         assert_eq!(actual, 2);
@@ -316,7 +710,7 @@ mod tests {
         sut.add_edge("B", "C");
 
         // Act
-        let actual = sut.count_possible_paths("A", "D");
+        let actual = sut.count_possible_paths("A", "D").unwrap();
 
         // Assert
         assert_eq!(actual, 3);
@@ -330,7 +724,7 @@ mod tests {
         sut.add_edge("C", "D");
 
         // Act
-        let actual = sut.count_possible_paths("A", "D");
+        let actual = sut.count_possible_paths("A", "D").unwrap();
 
         // Assert
         assert_eq!(actual, 0);
@@ -343,7 +737,7 @@ mod tests {
         sut.add_edge("A", "B");
 
         // Act
-        let actual = sut.count_possible_paths("A", "A");
+        let actual = sut.count_possible_paths("A", "A").unwrap();
 
         // Assert
         assert_eq!(actual, 1);
@@ -362,9 +756,254 @@ mod tests {
         sut.add_edge("D", "E");
 
         // Act
-        let actual = sut.count_possible_paths("A", "E");
+        let actual = sut.count_possible_paths("A", "E").unwrap();
 
         // Assert
         assert_eq!(actual, 5);
     }
+
+    fn graph(edges: &[(&str, &str)]) -> DirectedGraph {
+        let mut graph = DirectedGraph::new();
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_count_possible_paths_cycle() {
+        // Arrange
+        let sut = graph(&[("A", "B"), ("B", "C"), ("C", "B"), ("C", "D")]);
+
+        // Act
+        let actual = sut.count_possible_paths("A", "D");
+
+        // Assert
+        assert_eq!(
+            actual,
+            Err(GraphError::Cycle(vec!["B".to_string(), "C".to_string()]))
+        );
+        assert_eq!(
+            actual.unwrap_err().to_string(),
+            "graph contains a cycle: B -> C -> B"
+        );
+    }
+
+    #[test]
+    fn test_count_possible_paths_unknown_node() {
+        // Arrange
+        let sut = graph(&[("A", "B")]);
+
+        // Act
+        let actual = sut.count_possible_paths("A", "Z");
+
+        // Assert
+        assert_eq!(actual, Err(GraphError::UnknownNode("Z".to_string())));
+    }
+
+    #[test]
+    fn test_topological_order() {
+        // Arrange
+        let sut = graph(&[("C", "D"), ("A", "B"), ("B", "C"), ("A", "C")]);
+
+        // Act
+        let actual = sut.topological_order().unwrap();
+
+        // Assert
+        assert_eq!(actual, vec!["A", "B", "C", "D"]);
+        assert!(sut.is_acyclic());
+    }
+
+    #[test]
+    fn test_topological_order_self_loop() {
+        // Arrange
+        let sut = graph(&[("A", "B"), ("B", "B")]);
+
+        // Act
+        let actual = sut.topological_order();
+
+        // Assert
+        assert_eq!(actual, Err(GraphError::Cycle(vec!["B".to_string()])));
+        assert!(!sut.is_acyclic());
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        // Arrange
+        let sut = graph(&[
+            ("A", "B"),
+            ("B", "C"),
+            ("C", "A"),
+            ("C", "D"),
+            ("D", "E"),
+            ("E", "D"),
+            ("F", "E"),
+        ]);
+
+        // Act
+        let actual = sut.strongly_connected_components();
+
+        // Assert
+        assert_eq!(actual, vec![vec!["F"], vec!["A", "B", "C"], vec!["D", "E"]]);
+    }
+
+    #[test]
+    fn test_condensation() {
+        // Arrange
+        let sut = graph(&[("A", "B"), ("B", "A"), ("B", "C"), ("A", "C"), ("D", "D")]);
+
+        // Act
+        let actual = sut.condensation();
+
+        // Assert
+        assert_eq!(actual.components.len(), 3);
+        assert_eq!(actual.component_of("A"), actual.component_of("B"));
+        assert_ne!(actual.component_of("A"), actual.component_of("C"));
+        assert_eq!(actual.component_of("Z"), None);
+        assert!(actual.dag.is_acyclic());
+        assert_eq!(actual.dag.node_count(), 3);
+        assert_eq!(actual.dag.get_neighbors("A"), Some(vec!["C"]));
+        assert_eq!(actual.dag.get_neighbors("D"), Some(vec![]));
+    }
+
+    fn waypoint_graph() -> DirectedGraph {
+        graph(&[
+            ("S", "A"),
+            ("S", "B"),
+            ("A", "C"),
+            ("B", "C"),
+            ("C", "D"),
+            ("C", "E"),
+            ("D", "T"),
+            ("E", "T"),
+        ])
+    }
+
+    #[rstest]
+    #[case(&[], 4)]
+    #[case(&["A"], 2)]
+    #[case(&["A", "E"], 1)]
+    #[case(&["E", "A"], 1)]
+    #[case(&["A", "B"], 0)]
+    #[case(&["C", "C"], 4)]
+    #[case(&["S", "T"], 4)]
+    fn test_count_paths_via(#[case] waypoints: &[&str], #[case] expected: u128) {
+        // Arrange
+        let sut = waypoint_graph();
+
+        // Act
+        let actual = sut.count_paths_via("S", "T", waypoints);
+
+        // Assert
+        assert_eq!(actual, Ok(expected));
+    }
+
+    #[rstest]
+    #[case(&[], 4)]
+    #[case(&["A"], 2)]
+    #[case(&["A", "D"], 1)]
+    #[case(&["C"], 0)]
+    #[case(&["T"], 0)]
+    #[case(&["Z"], 4)]
+    fn test_count_paths_avoiding(#[case] avoided: &[&str], #[case] expected: u128) {
+        // Arrange
+        let sut = waypoint_graph();
+
+        // Act
+        let actual = sut.count_paths_avoiding("S", "T", avoided);
+
+        // Assert
+        assert_eq!(actual, Ok(expected));
+    }
+
+    #[test]
+    fn test_count_paths_via_unknown_waypoint() {
+        // Arrange
+        let sut = waypoint_graph();
+
+        // Act
+        let actual = sut.count_paths_via("S", "T", &["Z"]);
+
+        // Assert
+        assert_eq!(actual, Err(GraphError::UnknownNode("Z".to_string())));
+    }
+
+    #[test]
+    fn test_count_paths_via_many_waypoints() {
+        // Arrange
+        // A chain of 100 nodes with a shortcut around the middle one.
+        let names: Vec<String> = (0..100).map(|i| format!("n{i}")).collect();
+        let mut sut = DirectedGraph::new();
+        for pair in names.windows(2) {
+            sut.add_edge(&pair[0], &pair[1]);
+        }
+        sut.add_edge("n49", "n51");
+        let all: Vec<&str> = names.iter().map(String::as_str).collect();
+
+        // Act & Assert
+        assert_eq!(sut.count_paths_via("n0", "n99", &all[..50]), Ok(2));
+        assert_eq!(sut.count_paths_via("n0", "n99", &all), Ok(1));
+    }
+
+    #[test]
+    fn test_count_paths_via_does_not_overflow() {
+        // Arrange
+        // 80 diamonds in a row give 2^80 paths, more than fits in an i64.
+        let mut sut = DirectedGraph::new();
+        for i in 0..80 {
+            sut.add_edge(&format!("n{i}"), &format!("a{i}"));
+            sut.add_edge(&format!("n{i}"), &format!("b{i}"));
+            sut.add_edge(&format!("a{i}"), &format!("n{}", i + 1));
+            sut.add_edge(&format!("b{i}"), &format!("n{}", i + 1));
+        }
+
+        // Act
+        let actual = sut.count_paths_via("n0", "n80", &["n40"]);
+
+        // Assert
+        assert_eq!(actual, Ok(1 << 80));
+        assert_eq!(
+            sut.count_possible_paths("n0", "n80"),
+            Err(GraphError::TooManyPaths(1 << 80))
+        );
+    }
+
+    #[test]
+    fn test_count_paths_on_long_chain() {
+        // Arrange
+        // Far deeper than the call stack allows when recursing once per node.
+        let names: Vec<String> = (0..100_000).map(|i| format!("n{i}")).collect();
+        let mut sut = DirectedGraph::new();
+        for pair in names.windows(2) {
+            sut.add_edge(&pair[0], &pair[1]);
+        }
+
+        // Act
+        let actual = sut.count_paths_via("n0", "n99999", &["n50000"]);
+
+        // Assert
+        assert_eq!(actual, Ok(1));
+        assert_eq!(sut.count_possible_paths("n0", "n99999"), Ok(1));
+    }
+
+    #[test]
+    fn test_to_dot() {
+        // Arrange
+        let sut = graph(&[("you", "bbb"), ("bbb", "out"), ("you", "out")]);
+
+        // Act
+        let actual = sut.to_dot();
+
+        // Assert
+        let expected = r#"digraph {
+    0 [label="you"];
+    1 [label="bbb"];
+    2 [label="out"];
+    0 -> 1;
+    0 -> 2;
+    1 -> 2;
+}
+"#;
+        assert_eq!(actual, expected);
+    }
 }
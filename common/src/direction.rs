@@ -0,0 +1,284 @@
+use std::str::FromStr;
+
+use crate::Point;
+
+/// One of the four orthogonal directions, with y pointing down as in a grid.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// All directions in clockwise order, starting with up.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    /// Turns 90 degrees counterclockwise.
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    /// The offset of a single step in this direction as `(dx, dy)`.
+    pub fn delta(&self) -> (i64, i64) {
+        Direction8::from(*self).delta()
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|dir| dir == self).unwrap()
+    }
+}
+
+impl TryFrom<char> for Direction4 {
+    type Error = String;
+
+    /// Accepts `U/D/L/R`, `^v<>` and the compass letters `N/E/S/W`.
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            'U' | '^' | 'N' => Ok(Direction4::Up),
+            'R' | '>' | 'E' => Ok(Direction4::Right),
+            'D' | 'v' | 'S' => Ok(Direction4::Down),
+            'L' | '<' | 'W' => Ok(Direction4::Left),
+            _ => Err(format!("invalid direction '{ch}'")),
+        }
+    }
+}
+
+impl FromStr for Direction4 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => Direction4::try_from(ch),
+            _ => Err(format!("invalid direction '{s}'")),
+        }
+    }
+}
+
+/// One of the four orthogonal or four diagonal directions, with y pointing down as in a grid.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions in clockwise order, starting with up.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turns 45 degrees counterclockwise.
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    /// The offset of a single step in this direction as `(dx, dy)`.
+    pub fn delta(&self) -> (i64, i64) {
+        match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|dir| dir == self).unwrap()
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(dir: Direction4) -> Self {
+        match dir {
+            Direction4::Up => Direction8::Up,
+            Direction4::Right => Direction8::Right,
+            Direction4::Down => Direction8::Down,
+            Direction4::Left => Direction8::Left,
+        }
+    }
+}
+
+impl FromStr for Direction8 {
+    type Err = String;
+
+    /// Accepts everything [`Direction4`] does, plus the diagonal compass points `NE/SE/SW/NW`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Direction8::UpRight),
+            "SE" => Ok(Direction8::DownRight),
+            "SW" => Ok(Direction8::DownLeft),
+            "NW" => Ok(Direction8::UpLeft),
+            _ => s.parse::<Direction4>().map(Direction8::from),
+        }
+    }
+}
+
+impl Point {
+    /// The point `n` steps away in direction `dir`.
+    pub fn step<D>(&self, dir: D, n: i64) -> Self
+    where
+        D: Into<Direction8>,
+    {
+        let (dx, dy) = dir.into().delta();
+        self.translate(dx * n, dy * n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Direction4::Up, Direction4::Left, Direction4::Right, Direction4::Down)]
+    #[case(Direction4::Right, Direction4::Up, Direction4::Down, Direction4::Left)]
+    #[case(Direction4::Left, Direction4::Down, Direction4::Up, Direction4::Right)]
+    fn test_direction4_turns(
+        #[case] dir: Direction4,
+        #[case] left: Direction4,
+        #[case] right: Direction4,
+        #[case] opposite: Direction4,
+    ) {
+        // Act & Assert
+        assert_eq!(dir.turn_left(), left);
+        assert_eq!(dir.turn_right(), right);
+        assert_eq!(dir.opposite(), opposite);
+    }
+
+    #[rstest]
+    #[case(
+        Direction8::Up,
+        Direction8::UpLeft,
+        Direction8::UpRight,
+        Direction8::Down
+    )]
+    #[case(
+        Direction8::DownLeft,
+        Direction8::Down,
+        Direction8::Left,
+        Direction8::UpRight
+    )]
+    fn test_direction8_turns(
+        #[case] dir: Direction8,
+        #[case] left: Direction8,
+        #[case] right: Direction8,
+        #[case] opposite: Direction8,
+    ) {
+        // Act & Assert
+        assert_eq!(dir.turn_left(), left);
+        assert_eq!(dir.turn_right(), right);
+        assert_eq!(dir.opposite(), opposite);
+    }
+
+    #[rstest]
+    #[case("U", Direction4::Up)]
+    #[case("^", Direction4::Up)]
+    #[case("N", Direction4::Up)]
+    #[case("v", Direction4::Down)]
+    #[case("S", Direction4::Down)]
+    #[case("<", Direction4::Left)]
+    #[case("W", Direction4::Left)]
+    #[case("R", Direction4::Right)]
+    #[case(">", Direction4::Right)]
+    #[case("E", Direction4::Right)]
+    fn test_parse_direction4(#[case] input: &str, #[case] expected: Direction4) {
+        // Act
+        let actual: Direction4 = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case("x")]
+    #[case("UR")]
+    #[case("")]
+    fn test_parse_direction4_invalid(#[case] input: &str) {
+        // Act
+        let actual = input.parse::<Direction4>();
+
+        // Assert
+        assert!(actual.is_err());
+    }
+
+    #[rstest]
+    #[case("NE", Direction8::UpRight)]
+    #[case("SW", Direction8::DownLeft)]
+    #[case("^", Direction8::Up)]
+    fn test_parse_direction8(#[case] input: &str, #[case] expected: Direction8) {
+        // Act
+        let actual: Direction8 = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_delta_matches_opposite() {
+        // Act & Assert
+        for dir in Direction8::ALL {
+            let (dx, dy) = dir.delta();
+            assert_eq!(dir.opposite().delta(), (-dx, -dy));
+            assert_eq!(dir.is_diagonal(), dx != 0 && dy != 0);
+        }
+    }
+
+    #[test]
+    fn test_step() {
+        // Arrange
+        let point = Point::new(2, 3);
+
+        // Act & Assert
+        assert_eq!(point.step(Direction4::Up, 2), Point::new(2, 1));
+        assert_eq!(point.step(Direction8::DownLeft, 3), Point::new(-1, 6));
+        assert_eq!(point.step(Direction4::Right, 0), point);
+    }
+}
@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A union-find structure over the elements `0..len()`, with path compression and union by size.
+///
+/// Lookups compress paths, so even the read-only looking queries take `&mut self`.
+#[derive(Debug, PartialEq, Clone, Eq, Default)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    /// The number of elements in the component, only kept up to date for roots.
+    sizes: Vec<usize>,
+    component_count: usize,
+}
+
+impl DisjointSet {
+    /// Creates `len` elements, each in a component of its own.
+    pub fn new(len: usize) -> Self {
+        DisjointSet {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            component_count: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Adds an element in a component of its own and returns it.
+    pub fn add(&mut self) -> usize {
+        self.parents.push(self.parents.len());
+        self.sizes.push(1);
+        self.component_count += 1;
+        self.parents.len() - 1
+    }
+
+    /// The representative of the component containing `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = element;
+        while current != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+
+        root
+    }
+
+    /// Merges the components of `a` and `b`. Returns false if they were already the same component.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut root_a, mut root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return false;
+        }
+
+        if self.sizes[root_a] < self.sizes[root_b] {
+            std::mem::swap(&mut root_a, &mut root_b);
+        }
+        self.parents[root_b] = root_a;
+        self.sizes[root_a] += self.sizes[root_b];
+        self.component_count -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of elements in the component containing `element`.
+    pub fn size_of(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.component_count
+    }
+
+    /// The size of every component, largest first.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = (0..self.len())
+            .filter(|&element| self.parents[element] == element)
+            .map(|root| self.sizes[root])
+            .collect();
+        sizes.sort_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    /// The elements in the same component as `element`, in ascending order.
+    pub fn members(&mut self, element: usize) -> Vec<usize> {
+        let root = self.find(element);
+        (0..self.len())
+            .filter(|&other| self.find(other) == root)
+            .collect()
+    }
+
+    /// The elements of every component, ordered by their smallest element.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut component_of_root: HashMap<usize, usize> = HashMap::new();
+        let mut components: Vec<Vec<usize>> = vec![];
+        for element in 0..self.len() {
+            let root = self.find(element);
+            let index = *component_of_root.entry(root).or_insert_with(|| {
+                components.push(vec![]);
+                components.len() - 1
+            });
+            components[index].push(element);
+        }
+        components
+    }
+}
+
+/// A [`DisjointSet`] over arbitrary hashable values, which are added the first time they are seen.
+#[derive(Debug, Clone)]
+pub struct KeyedDisjointSet<T> {
+    set: DisjointSet,
+    values: Vec<T>,
+    indices: HashMap<T, usize>,
+}
+
+impl<T> Default for KeyedDisjointSet<T> {
+    fn default() -> Self {
+        KeyedDisjointSet {
+            set: DisjointSet::default(),
+            values: vec![],
+            indices: HashMap::new(),
+        }
+    }
+}
+
+impl<T> KeyedDisjointSet<T>
+where
+    T: Eq + Hash + Clone,
+{
+    pub fn new() -> Self {
+        KeyedDisjointSet::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.indices.contains_key(value)
+    }
+
+    /// Adds `value` in a component of its own, unless it is already known.
+    pub fn insert(&mut self, value: T) {
+        self.index_of(value);
+    }
+
+    /// The representative of the component containing `value`, or None if `value` is unknown.
+    pub fn find(&mut self, value: &T) -> Option<&T> {
+        let index = *self.indices.get(value)?;
+        let root = self.set.find(index);
+        Some(&self.values[root])
+    }
+
+    /// Merges the components of `a` and `b`, adding them first if unknown. Returns false if they
+    /// were already the same component.
+    pub fn union(&mut self, a: T, b: T) -> bool {
+        let (a, b) = (self.index_of(a), self.index_of(b));
+        self.set.union(a, b)
+    }
+
+    /// Checks if `a` and `b` are in the same component. Unknown values are only connected to
+    /// themselves.
+    pub fn connected(&mut self, a: &T, b: &T) -> bool {
+        match (self.indices.get(a), self.indices.get(b)) {
+            (Some(&a), Some(&b)) => self.set.connected(a, b),
+            _ => a == b,
+        }
+    }
+
+    /// The number of values in the component containing `value`, or 0 if `value` is unknown.
+    pub fn size_of(&mut self, value: &T) -> usize {
+        self.indices
+            .get(value)
+            .map_or(0, |&index| self.set.size_of(index))
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.set.component_count()
+    }
+
+    /// The size of every component, largest first.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        self.set.component_sizes()
+    }
+
+    /// The values in the same component as `value`, in insertion order, or an empty list if
+    /// `value` is unknown.
+    pub fn members(&mut self, value: &T) -> Vec<&T> {
+        let Some(&index) = self.indices.get(value) else {
+            return vec![];
+        };
+        self.set
+            .members(index)
+            .into_iter()
+            .map(|member| &self.values[member])
+            .collect()
+    }
+
+    /// The values of every component, in insertion order.
+    pub fn components(&mut self) -> Vec<Vec<&T>> {
+        self.set
+            .components()
+            .into_iter()
+            .map(|component| {
+                component
+                    .into_iter()
+                    .map(|index| &self.values[index])
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn index_of(&mut self, value: T) -> usize {
+        if let Some(&index) = self.indices.get(&value) {
+            return index;
+        }
+        let index = self.set.add();
+        self.values.push(value.clone());
+        self.indices.insert(value, index);
+        index
+    }
+}
+
+impl<T> FromIterator<T> for KeyedDisjointSet<T>
+where
+    T: Eq + Hash + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = KeyedDisjointSet::new();
+        for value in iter {
+            set.insert(value);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn set(unions: &[(usize, usize)]) -> DisjointSet {
+        let mut set = DisjointSet::new(8);
+        for &(a, b) in unions {
+            set.union(a, b);
+        }
+        set
+    }
+
+    #[test]
+    fn test_new_set_is_all_singletons() {
+        // Act
+        let mut actual = DisjointSet::new(3);
+
+        // Assert
+        assert_eq!(actual.component_count(), 3);
+        assert_eq!(actual.component_sizes(), vec![1, 1, 1]);
+        assert!(!actual.connected(0, 1));
+        assert_eq!(actual.find(2), 2);
+    }
+
+    #[rstest]
+    #[case(0, 2, true)]
+    #[case(1, 3, true)]
+    #[case(4, 5, true)]
+    #[case(0, 4, false)]
+    #[case(6, 6, true)]
+    #[case(6, 7, false)]
+    fn test_connected(#[case] a: usize, #[case] b: usize, #[case] expected: bool) {
+        // Arrange
+        let mut sut = set(&[(0, 1), (2, 3), (1, 3), (4, 5)]);
+
+        // Act
+        let actual = sut.connected(a, b);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_union_tracks_sizes_and_components() {
+        // Arrange
+        let mut sut = set(&[(0, 1), (2, 3), (1, 3), (4, 5)]);
+
+        // Act
+        let merged_again = sut.union(0, 2);
+
+        // Assert
+        assert!(!merged_again);
+        assert_eq!(sut.component_count(), 4);
+        assert_eq!(sut.component_sizes(), vec![4, 2, 1, 1]);
+        assert_eq!(sut.size_of(3), 4);
+        assert_eq!(sut.members(5), vec![4, 5]);
+        assert_eq!(
+            sut.components(),
+            vec![vec![0, 1, 2, 3], vec![4, 5], vec![6], vec![7]]
+        );
+    }
+
+    #[test]
+    fn test_long_chain_is_compressed() {
+        // Arrange
+        let mut sut = DisjointSet::new(1000);
+        for i in 1..1000 {
+            sut.union(i - 1, i);
+        }
+
+        // Act
+        let root = sut.find(999);
+
+        // Assert
+        assert_eq!(sut.component_count(), 1);
+        assert_eq!(sut.size_of(0), 1000);
+        assert_eq!(sut.parents[999], root);
+    }
+
+    #[test]
+    fn test_keyed_set() {
+        // Arrange
+        let mut sut: KeyedDisjointSet<&str> = ["a", "b", "c"].into_iter().collect();
+
+        // Act
+        sut.union("a", "c");
+        sut.union("d", "e");
+
+        // Assert
+        assert_eq!(sut.len(), 5);
+        assert_eq!(sut.component_count(), 3);
+        assert_eq!(sut.component_sizes(), vec![2, 2, 1]);
+        assert!(sut.connected(&"c", &"a"));
+        assert!(!sut.connected(&"a", &"b"));
+        assert!(!sut.connected(&"a", &"z"));
+        assert_eq!(sut.find(&"c"), Some(&"a"));
+        assert_eq!(sut.find(&"z"), None);
+        assert_eq!(sut.size_of(&"z"), 0);
+        assert_eq!(sut.members(&"e"), vec![&"d", &"e"]);
+        assert_eq!(
+            sut.components(),
+            vec![vec![&"a", &"c"], vec![&"b"], vec![&"d", &"e"]]
+        );
+    }
+}
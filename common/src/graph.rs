@@ -0,0 +1,490 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet, VecDeque};
+use std::fmt::{self, Write};
+use std::ops::Add;
+
+use crate::GraphError;
+
+/// An edge between two node indices carrying a weight or label.
+#[derive(Debug, PartialEq, Clone, Eq)]
+pub struct Edge<E> {
+    pub from: usize,
+    pub to: usize,
+    pub weight: E,
+}
+
+/// A graph with a payload `N` on every node and a weight `E` on every edge. Nodes are identified by
+/// the index returned from [`Graph::add_node`]; methods taking an index panic if it is out of range.
+///
+/// In an undirected graph every edge can be followed both ways.
+#[derive(Debug, PartialEq, Clone, Eq)]
+pub struct Graph<N, E> {
+    directed: bool,
+    nodes: Vec<N>,
+    edges: Vec<Edge<E>>,
+    /// For every node the indices into `edges` of the edges that can be followed from it.
+    adjacency: Vec<Vec<usize>>,
+}
+
+/// The distances from a start node found by a single-source shortest path search.
+#[derive(Debug, PartialEq, Clone, Eq)]
+pub struct ShortestPaths<E> {
+    start: usize,
+    distances: Vec<Option<E>>,
+    predecessors: Vec<Option<usize>>,
+}
+
+impl<E> ShortestPaths<E>
+where
+    E: Copy,
+{
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The distance from the start to `node`, or None if `node` is unreachable.
+    pub fn distance(&self, node: usize) -> Option<E> {
+        self.distances[node]
+    }
+
+    /// The nodes on a shortest path from the start to `node`, both included.
+    pub fn path_to(&self, node: usize) -> Option<Vec<usize>> {
+        self.distances[node]?;
+
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(previous) = self.predecessors[current] {
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<N, E> Graph<N, E> {
+    pub fn directed() -> Self {
+        Graph {
+            directed: true,
+            nodes: vec![],
+            edges: vec![],
+            adjacency: vec![],
+        }
+    }
+
+    pub fn undirected() -> Self {
+        Graph {
+            directed: false,
+            ..Graph::directed()
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Adds a node and returns its index.
+    pub fn add_node(&mut self, payload: N) -> usize {
+        self.nodes.push(payload);
+        self.adjacency.push(vec![]);
+        self.nodes.len() - 1
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: E) {
+        assert!(
+            from < self.nodes.len() && to < self.nodes.len(),
+            "Edge {from} -> {to} refers to an unknown node"
+        );
+        let edge_index = self.edges.len();
+        self.edges.push(Edge { from, to, weight });
+        self.adjacency[from].push(edge_index);
+        if !self.directed && from != to {
+            self.adjacency[to].push(edge_index);
+        }
+    }
+
+    pub fn node(&self, index: usize) -> &N {
+        &self.nodes[index]
+    }
+
+    pub fn node_mut(&mut self, index: usize) -> &mut N {
+        &mut self.nodes[index]
+    }
+
+    /// The index of the first node whose payload satisfies `predicate`.
+    pub fn find_node<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(&N) -> bool,
+    {
+        self.nodes.iter().position(predicate)
+    }
+
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn edges(&self) -> &[Edge<E>] {
+        &self.edges
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// The nodes reachable from `node` over a single edge, with the weight of that edge.
+    pub fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, &E)> {
+        self.adjacency[node].iter().map(move |&edge_index| {
+            let edge = &self.edges[edge_index];
+            let other = if edge.from == node {
+                edge.to
+            } else {
+                edge.from
+            };
+            (other, &edge.weight)
+        })
+    }
+
+    /// The nodes grouped by the number of edges on the shortest path from `start`, ignoring weights.
+    /// The first level only holds `start`; unreachable nodes are left out.
+    pub fn bfs_levels(&self, start: usize) -> Vec<Vec<usize>> {
+        let mut levels: Vec<Vec<usize>> = vec![];
+        let mut seen = vec![false; self.nodes.len()];
+        let mut queue: VecDeque<(usize, usize)> = VecDeque::from([(start, 0)]);
+        seen[start] = true;
+
+        while let Some((node, level)) = queue.pop_front() {
+            if levels.len() == level {
+                levels.push(vec![]);
+            }
+            levels[level].push(node);
+            for (next, _) in self.neighbors(node) {
+                if !seen[next] {
+                    seen[next] = true;
+                    queue.push_back((next, level + 1));
+                }
+            }
+        }
+
+        levels
+    }
+
+    /// All nodes reachable from `start`, including `start` itself.
+    pub fn reachable_from(&self, start: usize) -> HashSet<usize> {
+        self.bfs_levels(start).into_iter().flatten().collect()
+    }
+
+    /// Writes the graph in the Graphviz DOT language, labelling nodes and edges with the given
+    /// functions. Edges labelled None are written without a label.
+    pub fn to_dot_with<NL, EL>(&self, node_label: NL, edge_label: EL) -> String
+    where
+        NL: Fn(&N) -> String,
+        EL: Fn(&E) -> Option<String>,
+    {
+        let (keyword, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        let mut dot = format!("{keyword} {{\n");
+        for (index, node) in self.nodes.iter().enumerate() {
+            writeln!(
+                dot,
+                "    {index} [label={}];",
+                dot_string(&node_label(node))
+            )
+            .unwrap();
+        }
+        for edge in &self.edges {
+            write!(dot, "    {} {arrow} {}", edge.from, edge.to).unwrap();
+            if let Some(label) = edge_label(&edge.weight) {
+                write!(dot, " [label={}]", dot_string(&label)).unwrap();
+            }
+            dot.push_str(";\n");
+        }
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    /// Writes the graph in the Graphviz DOT language, labelling nodes with their payload and edges
+    /// with their weight.
+    pub fn to_dot(&self) -> String
+    where
+        N: fmt::Display,
+        E: fmt::Display,
+    {
+        self.to_dot_with(|node| node.to_string(), |weight| Some(weight.to_string()))
+    }
+}
+
+impl<N, E> Graph<N, E>
+where
+    E: Copy + Ord + Add<Output = E> + Default,
+{
+    /// Dijkstra's algorithm from `start`. All weights must be non-negative, where `E::default()` is
+    /// taken as zero; use [`Graph::bellman_ford`] otherwise.
+    pub fn dijkstra(&self, start: usize) -> ShortestPaths<E> {
+        let mut distances: Vec<Option<E>> = vec![None; self.nodes.len()];
+        let mut predecessors: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut heap = BinaryHeap::from([Reverse((E::default(), start))]);
+        distances[start] = Some(E::default());
+
+        while let Some(Reverse((distance, node))) = heap.pop() {
+            if distances[node].is_some_and(|best| best < distance) {
+                continue;
+            }
+            for (next, &weight) in self.neighbors(node) {
+                let next_distance = distance + weight;
+                if distances[next].is_none_or(|best| next_distance < best) {
+                    distances[next] = Some(next_distance);
+                    predecessors[next] = Some(node);
+                    heap.push(Reverse((next_distance, next)));
+                }
+            }
+        }
+
+        ShortestPaths {
+            start,
+            distances,
+            predecessors,
+        }
+    }
+
+    /// The Bellman-Ford algorithm from `start`, which allows negative weights. Fails with
+    /// [`GraphError::NegativeCycle`] if a negative cycle is reachable from `start`.
+    pub fn bellman_ford(&self, start: usize) -> Result<ShortestPaths<E>, GraphError> {
+        let mut distances: Vec<Option<E>> = vec![None; self.nodes.len()];
+        let mut predecessors: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        distances[start] = Some(E::default());
+
+        for round in 0..self.nodes.len() {
+            let mut changed = false;
+            for node in 0..self.nodes.len() {
+                let Some(distance) = distances[node] else {
+                    continue;
+                };
+                for (next, &weight) in self.neighbors(node) {
+                    let next_distance = distance + weight;
+                    if distances[next].is_none_or(|best| next_distance < best) {
+                        distances[next] = Some(next_distance);
+                        predecessors[next] = Some(node);
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+            // Without negative cycles every shortest path is found within `node_count - 1` rounds.
+            if round + 1 == self.nodes.len() {
+                return Err(GraphError::NegativeCycle);
+            }
+        }
+
+        Ok(ShortestPaths {
+            start,
+            distances,
+            predecessors,
+        })
+    }
+
+    /// The Floyd-Warshall algorithm, where `result[from][to]` is the shortest distance between the
+    /// two nodes, or None if `to` is unreachable from `from`. Fails with
+    /// [`GraphError::NegativeCycle`] if the graph contains a negative cycle.
+    pub fn floyd_warshall(&self) -> Result<Vec<Vec<Option<E>>>, GraphError> {
+        let n = self.nodes.len();
+        let mut distances: Vec<Vec<Option<E>>> = vec![vec![None; n]; n];
+        for (node, row) in distances.iter_mut().enumerate() {
+            row[node] = Some(E::default());
+            for (next, &weight) in self.neighbors(node) {
+                if row[next].is_none_or(|best| weight < best) {
+                    row[next] = Some(weight);
+                }
+            }
+        }
+
+        for via in 0..n {
+            let from_via = distances[via].clone();
+            for row in distances.iter_mut() {
+                let Some(to_via) = row[via] else {
+                    continue;
+                };
+                for (to, &onward) in from_via.iter().enumerate() {
+                    if let Some(onward) = onward {
+                        let distance = to_via + onward;
+                        if row[to].is_none_or(|best| distance < best) {
+                            row[to] = Some(distance);
+                        }
+                    }
+                }
+            }
+        }
+
+        if (0..n).any(|node| distances[node][node].is_some_and(|d| d < E::default())) {
+            return Err(GraphError::NegativeCycle);
+        }
+
+        Ok(distances)
+    }
+}
+
+/// Quotes `s` as a DOT string.
+fn dot_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// A directed graph with nodes "A" to "E"; "E" is unreachable from "A".
+    fn weighted() -> Graph<&'static str, i64> {
+        let mut graph = Graph::directed();
+        for name in ["A", "B", "C", "D", "E"] {
+            graph.add_node(name);
+        }
+        graph.add_edge(0, 1, 4);
+        graph.add_edge(0, 2, 1);
+        graph.add_edge(2, 1, 2);
+        graph.add_edge(1, 3, 1);
+        graph.add_edge(2, 3, 5);
+        graph.add_edge(4, 0, 1);
+        graph
+    }
+
+    #[rstest]
+    #[case(0, Some(0), Some(vec![0]))]
+    #[case(1, Some(3), Some(vec![0, 2, 1]))]
+    #[case(3, Some(4), Some(vec![0, 2, 1, 3]))]
+    #[case(4, None, None)]
+    fn test_dijkstra(
+        #[case] node: usize,
+        #[case] expected_distance: Option<i64>,
+        #[case] expected_path: Option<Vec<usize>>,
+    ) {
+        // Act
+        let actual = weighted().dijkstra(0);
+
+        // Assert
+        assert_eq!(actual.distance(node), expected_distance);
+        assert_eq!(actual.path_to(node), expected_path);
+        assert_eq!(weighted().bellman_ford(0), Ok(actual));
+    }
+
+    #[test]
+    fn test_bellman_ford_negative_weights() {
+        // Arrange
+        let mut graph = weighted();
+        graph.add_edge(3, 2, -3);
+
+        // Act
+        let actual = graph.bellman_ford(0).unwrap();
+
+        // Assert
+        assert_eq!(actual.distance(2), Some(1));
+        assert_eq!(actual.distance(3), Some(4));
+
+        // Arrange
+        graph.add_edge(3, 0, -5);
+
+        // Act & Assert
+        assert_eq!(graph.bellman_ford(0), Err(GraphError::NegativeCycle));
+        assert_eq!(graph.floyd_warshall(), Err(GraphError::NegativeCycle));
+    }
+
+    #[test]
+    fn test_floyd_warshall() {
+        // Act
+        let actual = weighted().floyd_warshall().unwrap();
+
+        // Assert
+        assert_eq!(actual[0], vec![Some(0), Some(3), Some(1), Some(4), None]);
+        assert_eq!(actual[4][3], Some(5));
+        assert_eq!(actual[3][0], None);
+        for (start, row) in actual.iter().enumerate() {
+            let single_source = weighted().dijkstra(start);
+            let expected: Vec<Option<i64>> = (0..5).map(|n| single_source.distance(n)).collect();
+            assert_eq!(row, &expected);
+        }
+    }
+
+    #[test]
+    fn test_undirected() {
+        // Arrange
+        let mut graph: Graph<&str, u32> = Graph::undirected();
+        let a = graph.add_node("A");
+        let b = graph.add_node("B");
+        let c = graph.add_node("C");
+        graph.add_edge(a, b, 7);
+        graph.add_edge(b, c, 2);
+
+        // Act
+        let actual = graph.dijkstra(c);
+
+        // Assert
+        assert_eq!(actual.distance(a), Some(9));
+        assert_eq!(actual.path_to(a), Some(vec![c, b, a]));
+        assert_eq!(
+            graph.neighbors(b).collect::<Vec<_>>(),
+            vec![(a, &7), (c, &2)]
+        );
+    }
+
+    #[test]
+    fn test_bfs_levels_and_reachability() {
+        // Arrange
+        let graph = weighted();
+
+        // Act
+        let actual = graph.bfs_levels(0);
+
+        // Assert
+        assert_eq!(actual, vec![vec![0], vec![1, 2], vec![3]]);
+        assert_eq!(graph.reachable_from(0), HashSet::from([0, 1, 2, 3]));
+        assert_eq!(graph.reachable_from(3), HashSet::from([3]));
+        assert_eq!(graph.reachable_from(4).len(), 5);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        // Arrange
+        let mut graph: Graph<&str, i64> = Graph::directed();
+        let you = graph.add_node("you");
+        let out = graph.add_node("\"out\"");
+        graph.add_edge(you, out, 3);
+
+        // Act
+        let actual = graph.to_dot();
+
+        // Assert
+        assert_eq!(
+            actual,
+            "digraph {\n    0 [label=\"you\"];\n    1 [label=\"\\\"out\\\"\"];\n    0 -> 1 [label=\"3\"];\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_to_dot_undirected_without_edge_labels() {
+        // Arrange
+        let mut graph: Graph<char, ()> = Graph::undirected();
+        graph.add_node('a');
+        graph.add_node('b');
+        graph.add_edge(0, 1, ());
+
+        // Act
+        let actual = graph.to_dot_with(|node| node.to_string(), |_| None);
+
+        // Assert
+        assert_eq!(
+            actual,
+            "graph {\n    0 [label=\"a\"];\n    1 [label=\"b\"];\n    0 -- 1;\n}\n"
+        );
+    }
+}
@@ -2,7 +2,7 @@ use std::str::Lines;
 
 use crate::Point;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Grid<T> {
     data: Vec<Vec<T>>,
 }
@@ -17,17 +17,72 @@ where
             .collect();
         Grid { data }
     }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its rows, where the first row is at y = 0.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        Grid { data: rows }
+    }
+
+    /// Creates a grid of the given dimensions where every cell is the result of `f` on its position.
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(Point) -> T,
+    {
+        let data = (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| f(Point::new(x as i64, y as i64)))
+                    .collect()
+            })
+            .collect();
+        Grid { data }
+    }
+
+    /// Like [`Grid::from_fn`], but returns None as soon as `f` returns None for a position.
+    pub fn try_from_fn<F>(width: usize, height: usize, mut f: F) -> Option<Self>
+    where
+        F: FnMut(Point) -> Option<T>,
+    {
+        let data = (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| f(Point::new(x as i64, y as i64)))
+                    .collect()
+            })
+            .collect::<Option<_>>()?;
+        Some(Grid { data })
+    }
 
     pub fn at(&self, x: usize, y: usize) -> Option<&T> {
         self.data.get(y).and_then(|row| row.get(x))
     }
 
     pub fn at_point(&self, p: &Point) -> Option<&T> {
+        if !self.contains(p) {
+            return None;
+        }
         self.at(p.x as usize, p.y as usize)
     }
 
+    pub fn at_mut(&mut self, p: &Point) -> Option<&mut T> {
+        if !self.contains(p) {
+            return None;
+        }
+        self.data
+            .get_mut(p.y as usize)
+            .and_then(|row| row.get_mut(p.x as usize))
+    }
+
+    /// Checks if `p` lies within the grid.
+    pub fn contains(&self, p: &Point) -> bool {
+        p.x >= 0 && p.y >= 0 && self.at(p.x as usize, p.y as usize).is_some()
+    }
+
+    /// The length of the longest row, so ragged grids report the width that covers every cell.
     pub fn width(&self) -> usize {
-        self.data.first().map_or(0, |row| row.len())
+        self.data.iter().map(|row| row.len()).max().unwrap_or(0)
     }
 
     pub fn height(&self) -> usize {
@@ -35,7 +90,90 @@ where
     }
 
     pub fn remove_at(&mut self, pos: &Point, clear_char: T) {
-        self.data[pos.y as usize][pos.x as usize] = clear_char;
+        self.set(pos, clear_char);
+    }
+
+    /// Overwrites the value at `pos`. Panics if `pos` is outside the grid.
+    pub fn set(&mut self, pos: &Point, value: T) {
+        *self
+            .at_mut(pos)
+            .unwrap_or_else(|| panic!("Point {pos:?} is outside the grid")) = value;
+    }
+
+    /// Iterates over the rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.iter().map(|row| row.as_slice())
+    }
+
+    /// Iterates over the columns from left to right, each column from top to bottom.
+    /// Rows that are too short to reach a column are skipped for that column.
+    pub fn columns(&self) -> impl Iterator<Item = Vec<&T>> {
+        (0..self.width()).map(move |x| self.column(x))
+    }
+
+    /// The cells of column `x` from top to bottom.
+    pub fn column(&self, x: usize) -> Vec<&T> {
+        self.data.iter().filter_map(|row| row.get(x)).collect()
+    }
+
+    /// Iterates over all cells in reading order: left to right, top to bottom.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.data.iter().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, value)| (Point::new(x as i64, y as i64), value))
+        })
+    }
+
+    /// The horizontally and vertically adjacent cells of `p` that lie within the grid,
+    /// in reading order.
+    pub fn neighbors4(&self, p: &Point) -> impl Iterator<Item = (Point, &T)> {
+        let center = *p;
+        [(0, -1), (-1, 0), (1, 0), (0, 1)]
+            .into_iter()
+            .map(move |(dx, dy)| center.translate(dx, dy))
+            .filter_map(|neighbor| self.at_point(&neighbor).map(|value| (neighbor, value)))
+    }
+
+    /// All 8 surrounding cells of `p` that lie within the grid, in reading order.
+    pub fn neighbors8(&self, p: &Point) -> impl Iterator<Item = (Point, &T)> {
+        let neighbors: Vec<Point> = p.adjacent_points().collect();
+        neighbors
+            .into_iter()
+            .filter_map(|neighbor| self.at_point(&neighbor).map(|value| (neighbor, value)))
+    }
+
+    /// The positions of all cells for which `predicate` holds, in reading order.
+    pub fn positions_of<'a, F>(&'a self, predicate: F) -> impl Iterator<Item = Point> + 'a
+    where
+        F: Fn(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter(move |(_, value)| predicate(value))
+            .map(|(p, _)| p)
+    }
+
+    /// Creates a grid of the same dimensions by applying `f` to every cell.
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        let data = self
+            .data
+            .iter()
+            .map(|row| row.iter().map(&mut f).collect())
+            .collect();
+        Grid { data }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: PartialEq,
+{
+    /// The position of the first cell equal to `value`, in reading order.
+    pub fn find(&self, value: &T) -> Option<Point> {
+        self.positions_of(|cell| cell == value).next()
     }
 }
 
@@ -82,6 +220,18 @@ mod tests {
         assert_eq!(grid.height(), 3);
     }
 
+    #[test]
+    fn test_width_of_ragged_grid() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("12\n3456\n7\n".lines());
+
+        // Act
+        let actual = grid.width();
+
+        // Assert
+        assert_eq!(actual, 4);
+    }
+
     #[test]
     fn test_empty_grid() {
         // Arrange
@@ -108,4 +258,151 @@ mod tests {
         // Assert
         assert_eq!(*grid.at(0, 0).unwrap(), '.');
     }
+
+    #[test]
+    fn test_at_point_negative() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("12\n34\n".lines());
+
+        // Act
+        let actual = grid.at_point(&Point::new(-1, 0));
+
+        // Assert
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn test_set_and_at_mut() {
+        // Arrange
+        let mut grid: Grid<char> = Grid::parse("12\n34\n".lines());
+
+        // Act
+        grid.set(&Point::new(1, 0), 'x');
+        *grid.at_mut(&Point::new(0, 1)).unwrap() = 'y';
+
+        // Assert
+        assert_eq!(grid, Grid::parse("1x\ny4\n".lines()));
+        assert_eq!(grid.at_mut(&Point::new(2, 0)), None);
+    }
+
+    #[test]
+    fn test_iter() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("12\n34\n".lines());
+
+        // Act
+        let actual: Vec<(Point, &char)> = grid.iter().collect();
+
+        // Assert
+        let expected = vec![
+            (Point::new(0, 0), &'1'),
+            (Point::new(1, 0), &'2'),
+            (Point::new(0, 1), &'3'),
+            (Point::new(1, 1), &'4'),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_neighbors4() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("123\n456\n789\n".lines());
+
+        // Act
+        let center: Vec<char> = grid
+            .neighbors4(&Point::new(1, 1))
+            .map(|(_, &ch)| ch)
+            .collect();
+        let corner: Vec<Point> = grid.neighbors4(&Point::new(0, 0)).map(|(p, _)| p).collect();
+
+        // Assert
+        assert_eq!(center, vec!['2', '4', '6', '8']);
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+    }
+
+    #[test]
+    fn test_neighbors8() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("123\n456\n789\n".lines());
+
+        // Act
+        let center: Vec<char> = grid
+            .neighbors8(&Point::new(1, 1))
+            .map(|(_, &ch)| ch)
+            .collect();
+        let corner: Vec<char> = grid
+            .neighbors8(&Point::new(2, 2))
+            .map(|(_, &ch)| ch)
+            .collect();
+
+        // Assert
+        assert_eq!(center, vec!['1', '2', '3', '4', '6', '7', '8', '9']);
+        assert_eq!(corner, vec!['5', '6', '8']);
+    }
+
+    #[test]
+    fn test_find() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("..S\n.S.\n".lines());
+
+        // Act & Assert
+        assert_eq!(grid.find(&'S'), Some(Point::new(2, 0)));
+        assert_eq!(grid.find(&'X'), None);
+    }
+
+    #[test]
+    fn test_positions_of() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("@.@\n.@.\n".lines());
+
+        // Act
+        let actual: Vec<Point> = grid.positions_of(|&ch| ch == '@').collect();
+
+        // Assert
+        let expected = vec![Point::new(0, 0), Point::new(2, 0), Point::new(1, 1)];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        // Act
+        let actual: Grid<i64> = Grid::from_fn(3, 2, |p| p.x * 10 + p.y);
+
+        // Assert
+        assert_eq!(
+            actual,
+            Grid::from_rows(vec![vec![0, 10, 20], vec![1, 11, 21]])
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("123\n456\n".lines());
+
+        // Act
+        let rows: Vec<&[char]> = grid.rows().collect();
+        let columns: Vec<Vec<&char>> = grid.columns().collect();
+
+        // Assert
+        assert_eq!(rows, vec![&['1', '2', '3'][..], &['4', '5', '6'][..]]);
+        assert_eq!(
+            columns,
+            vec![vec![&'1', &'4'], vec![&'2', &'5'], vec![&'3', &'6']]
+        );
+    }
+
+    #[test]
+    fn test_map() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("12\n34\n".lines());
+
+        // Act
+        let actual: Grid<u32> = grid.map(|ch| ch.to_digit(10).unwrap() * 2);
+
+        // Assert
+        assert_eq!(actual.at(1, 1), Some(&8));
+        assert_eq!(actual.width(), 2);
+        assert_eq!(actual.height(), 2);
+    }
 }
@@ -0,0 +1,331 @@
+use std::fmt;
+use std::str::Lines;
+
+use crate::{Cuboid, Grid, Point3d};
+
+/// A dense 3D grid, stored layer by layer. Every layer has the same width and height.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Grid3d<T> {
+    width: usize,
+    height: usize,
+    depth: usize,
+    data: Vec<T>,
+}
+
+impl<T> Grid3d<T>
+where
+    T: From<char>,
+{
+    /// Parses layers of text separated by blank lines, where the first layer is at z = 0.
+    /// Returns an error if the layers don't all have the same dimensions.
+    pub fn parse(lines: Lines) -> Result<Self, String> {
+        let mut layers: Vec<Vec<&str>> = vec![vec![]];
+        for line in lines {
+            if line.trim().is_empty() {
+                if !layers.last().unwrap().is_empty() {
+                    layers.push(vec![]);
+                }
+            } else {
+                layers.last_mut().unwrap().push(line);
+            }
+        }
+        if layers.last().unwrap().is_empty() {
+            layers.pop();
+        }
+
+        let height = layers.first().map_or(0, |layer| layer.len());
+        let width = layers
+            .first()
+            .and_then(|layer| layer.first())
+            .map_or(0, |row| row.chars().count());
+        let mut data = Vec::with_capacity(width * height * layers.len());
+        for (z, layer) in layers.iter().enumerate() {
+            if layer.len() != height {
+                return Err(format!(
+                    "layer {z} has {} rows, expected {height}",
+                    layer.len()
+                ));
+            }
+            for (y, row) in layer.iter().enumerate() {
+                if row.chars().count() != width {
+                    return Err(format!(
+                        "row {y} of layer {z} has width {}, expected {width}",
+                        row.chars().count()
+                    ));
+                }
+                data.extend(row.chars().map(T::from));
+            }
+        }
+
+        Ok(Grid3d {
+            width,
+            height,
+            depth: layers.len(),
+            data,
+        })
+    }
+}
+
+impl<T> Grid3d<T> {
+    /// Creates a grid of the given dimensions where every cell is the result of `f` on its position.
+    pub fn from_fn<F>(width: usize, height: usize, depth: usize, mut f: F) -> Self
+    where
+        F: FnMut(Point3d) -> T,
+    {
+        let mut data = Vec::with_capacity(width * height * depth);
+        for z in 0..depth {
+            for y in 0..height {
+                for x in 0..width {
+                    data.push(f(Point3d::new(x as i64, y as i64, z as i64)));
+                }
+            }
+        }
+        Grid3d {
+            width,
+            height,
+            depth,
+            data,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The cuboid covering all cells, or None for an empty grid.
+    pub fn bounds(&self) -> Option<Cuboid> {
+        if self.data.is_empty() {
+            return None;
+        }
+        Some(Cuboid::new(
+            &Point3d::new(0, 0, 0),
+            &Point3d::new(
+                self.width as i64 - 1,
+                self.height as i64 - 1,
+                self.depth as i64 - 1,
+            ),
+        ))
+    }
+
+    pub fn contains(&self, p: &Point3d) -> bool {
+        self.index_of(p).is_some()
+    }
+
+    pub fn at(&self, p: &Point3d) -> Option<&T> {
+        self.index_of(p).map(|index| &self.data[index])
+    }
+
+    pub fn at_mut(&mut self, p: &Point3d) -> Option<&mut T> {
+        self.index_of(p).map(|index| &mut self.data[index])
+    }
+
+    /// Overwrites the value at `p`. Panics if `p` is outside the grid.
+    pub fn set(&mut self, p: &Point3d, value: T) {
+        *self
+            .at_mut(p)
+            .unwrap_or_else(|| panic!("Point {p:?} is outside the grid")) = value;
+    }
+
+    /// Iterates over all cells, layer by layer and each layer in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point3d, &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (self.point_of(index), value))
+    }
+
+    /// The face-adjacent cells of `p` that lie within the grid.
+    pub fn neighbors6(&self, p: &Point3d) -> impl Iterator<Item = (Point3d, &T)> {
+        p.neighbors6()
+            .filter_map(|neighbor| self.at(&neighbor).map(|value| (neighbor, value)))
+    }
+
+    /// All surrounding cells of `p` that lie within the grid.
+    pub fn neighbors26(&self, p: &Point3d) -> impl Iterator<Item = (Point3d, &T)> {
+        p.neighbors26()
+            .filter_map(|neighbor| self.at(&neighbor).map(|value| (neighbor, value)))
+    }
+
+    /// The positions of all cells for which `predicate` holds, in the order of [`Grid3d::iter`].
+    pub fn positions_of<'a, F>(&'a self, predicate: F) -> impl Iterator<Item = Point3d> + 'a
+    where
+        F: Fn(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter(move |(_, value)| predicate(value))
+            .map(|(p, _)| p)
+    }
+
+    /// Creates a grid of the same dimensions by applying `f` to every cell.
+    pub fn map<U, F>(&self, f: F) -> Grid3d<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid3d {
+            width: self.width,
+            height: self.height,
+            depth: self.depth,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    /// The 2D slice at depth `z`, or None if `z` is outside the grid.
+    pub fn layer(&self, z: usize) -> Option<Grid<T>>
+    where
+        T: Clone,
+    {
+        if z >= self.depth {
+            return None;
+        }
+        let layer_size = self.width * self.height;
+        let rows = self.data[z * layer_size..(z + 1) * layer_size]
+            .chunks(self.width.max(1))
+            .map(|row| row.to_vec())
+            .collect();
+        Some(Grid::from_rows(rows))
+    }
+
+    fn index_of(&self, p: &Point3d) -> Option<usize> {
+        let in_bounds = (0..self.width as i64).contains(&p.x)
+            && (0..self.height as i64).contains(&p.y)
+            && (0..self.depth as i64).contains(&p.z);
+        in_bounds.then(|| (p.z as usize * self.height + p.y as usize) * self.width + p.x as usize)
+    }
+
+    fn point_of(&self, index: usize) -> Point3d {
+        let layer_size = self.width * self.height;
+        Point3d::new(
+            (index % self.width) as i64,
+            (index % layer_size / self.width) as i64,
+            (index / layer_size) as i64,
+        )
+    }
+}
+
+/// Writes the layers separated by blank lines, so for single character cells the output parses
+/// back into the same grid with `Grid3d::parse`.
+impl<T> fmt::Display for Grid3d<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for z in 0..self.depth {
+            if z > 0 {
+                writeln!(f)?;
+            }
+            for y in 0..self.height {
+                for x in 0..self.width {
+                    let p = Point3d::new(x as i64, y as i64, z as i64);
+                    write!(f, "{}", self.at(&p).unwrap())?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static LAYERS: &str = "#..
+.#.
+
+...
+..#
+";
+
+    #[test]
+    fn test_parse() {
+        // Act
+        let grid: Grid3d<char> = Grid3d::parse(LAYERS.lines()).unwrap();
+
+        // Assert
+        assert_eq!((grid.width(), grid.height(), grid.depth()), (3, 2, 2));
+        assert_eq!(grid.at(&Point3d::new(1, 1, 0)), Some(&'#'));
+        assert_eq!(grid.at(&Point3d::new(2, 1, 1)), Some(&'#'));
+        assert_eq!(grid.at(&Point3d::new(0, 0, 2)), None);
+    }
+
+    #[test]
+    fn test_parse_inconsistent_layers() {
+        // Act
+        let actual = Grid3d::<char>::parse("##\n##\n\n##\n".lines());
+
+        // Assert
+        assert_eq!(actual, Err("layer 1 has 1 rows, expected 2".to_string()));
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        // Arrange
+        let grid: Grid3d<char> = Grid3d::parse(LAYERS.lines()).unwrap();
+
+        // Act
+        let actual = grid.to_string();
+
+        // Assert
+        assert_eq!(actual, LAYERS);
+    }
+
+    #[test]
+    fn test_iter_and_positions_of() {
+        // Arrange
+        let grid: Grid3d<char> = Grid3d::parse(LAYERS.lines()).unwrap();
+
+        // Act
+        let actual: Vec<Point3d> = grid.positions_of(|&ch| ch == '#').collect();
+
+        // Assert
+        let expected = vec![
+            Point3d::new(0, 0, 0),
+            Point3d::new(1, 1, 0),
+            Point3d::new(2, 1, 1),
+        ];
+        assert_eq!(actual, expected);
+        assert_eq!(grid.iter().count(), 12);
+    }
+
+    #[test]
+    fn test_neighbors6_at_corner() {
+        // Arrange
+        let grid: Grid3d<i64> = Grid3d::from_fn(2, 2, 2, |p| p.x + p.y + p.z);
+
+        // Act
+        let actual: Vec<i64> = grid
+            .neighbors6(&Point3d::new(0, 0, 0))
+            .map(|(_, &value)| value)
+            .collect();
+
+        // Assert
+        assert_eq!(actual, vec![1, 1, 1]);
+        assert_eq!(grid.neighbors26(&Point3d::new(0, 0, 0)).count(), 7);
+    }
+
+    #[test]
+    fn test_set_and_layer() {
+        // Arrange
+        let mut grid: Grid3d<char> = Grid3d::from_fn(2, 2, 2, |_| '.');
+
+        // Act
+        grid.set(&Point3d::new(1, 0, 1), '#');
+
+        // Assert
+        assert_eq!(grid.layer(1), Some(Grid::parse(".#\n..\n".lines())));
+        assert_eq!(grid.layer(2), None);
+        assert_eq!(
+            grid.bounds(),
+            Some(Cuboid::new(&Point3d::new(0, 0, 0), &Point3d::new(1, 1, 1)))
+        );
+    }
+}
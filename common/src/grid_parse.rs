@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::Lines;
+
+use crate::{Grid, Point};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum GridParseError<E> {
+    /// The cell mapping rejected the character at `point`.
+    InvalidCell { point: Point, ch: char, error: E },
+    /// Row `y` is not as wide as the first row, only reported with [`RowPolicy::Strict`].
+    RaggedRow {
+        y: usize,
+        expected_width: usize,
+        actual_width: usize,
+    },
+}
+
+impl<E> fmt::Display for GridParseError<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridParseError::InvalidCell { point, ch, error } => {
+                write!(
+                    f,
+                    "invalid cell '{ch}' at ({}, {}): {error}",
+                    point.x, point.y
+                )
+            }
+            GridParseError::RaggedRow {
+                y,
+                expected_width,
+                actual_width,
+            } => write!(
+                f,
+                "row {y} has width {actual_width}, expected {expected_width}"
+            ),
+        }
+    }
+}
+
+impl<E> std::error::Error for GridParseError<E> where E: fmt::Debug + fmt::Display {}
+
+/// How rows that are shorter or longer than the others are handled while parsing.
+#[derive(Debug, PartialEq, Clone)]
+pub enum RowPolicy<T> {
+    /// Rows keep their own length.
+    Ragged,
+    /// Short rows are padded with the value up to the length of the longest row.
+    Pad(T),
+    /// Every row must be as wide as the first row.
+    Strict,
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid by mapping every character and its position with `f`. Rows keep their own length.
+    pub fn parse_with<F, E>(lines: Lines, mut f: F) -> Result<Self, GridParseError<E>>
+    where
+        F: FnMut(char, Point) -> Result<T, E>,
+    {
+        let data = lines
+            .enumerate()
+            .map(|(y, line)| parse_row(line, y, &mut f))
+            .collect::<Result<_, _>>()?;
+        Ok(Grid::from_rows(data))
+    }
+
+    /// Like [`Grid::parse_with`], with rows of different lengths handled according to `policy`.
+    pub fn parse_with_policy<F, E>(
+        lines: Lines,
+        policy: RowPolicy<T>,
+        mut f: F,
+    ) -> Result<Self, GridParseError<E>>
+    where
+        T: Clone,
+        F: FnMut(char, Point) -> Result<T, E>,
+    {
+        let mut data: Vec<Vec<T>> = Vec::new();
+        for (y, line) in lines.enumerate() {
+            if let (RowPolicy::Strict, Some(first_row)) = (&policy, data.first()) {
+                let actual_width = line.chars().count();
+                if actual_width != first_row.len() {
+                    return Err(GridParseError::RaggedRow {
+                        y,
+                        expected_width: first_row.len(),
+                        actual_width,
+                    });
+                }
+            }
+            data.push(parse_row(line, y, &mut f)?);
+        }
+
+        if let RowPolicy::Pad(fill) = policy {
+            let width = data.iter().map(|row| row.len()).max().unwrap_or(0);
+            for row in data.iter_mut() {
+                row.resize(width, fill.clone());
+            }
+        }
+
+        Ok(Grid::from_rows(data))
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: From<char> + Clone,
+{
+    /// Parses a grid where short rows, e.g. with trailing spaces trimmed, are padded with `fill`.
+    pub fn parse_padded(lines: Lines, fill: T) -> Self {
+        Self::parse_with_policy(lines, RowPolicy::Pad(fill), |ch, _| {
+            Ok::<T, Infallible>(T::from(ch))
+        })
+        .unwrap_or_else(unreachable_error)
+    }
+
+    /// Parses a grid where the cells containing one of `specials` are replaced by `background`.
+    /// Returns the positions of every special character found, in reading order.
+    pub fn parse_with_specials(
+        lines: Lines,
+        specials: &[char],
+        background: T,
+    ) -> (Self, HashMap<char, Vec<Point>>) {
+        let mut found: HashMap<char, Vec<Point>> = HashMap::new();
+        let grid = Self::parse_with(lines, |ch, point| {
+            if specials.contains(&ch) {
+                found.entry(ch).or_default().push(point);
+                Ok::<T, Infallible>(background.clone())
+            } else {
+                Ok(T::from(ch))
+            }
+        })
+        .unwrap_or_else(unreachable_error);
+        (grid, found)
+    }
+}
+
+/// Cell mappings that cannot fail only leave ragged rows, which are not reported without strict mode.
+fn unreachable_error<T>(error: GridParseError<Infallible>) -> T {
+    match error {
+        GridParseError::InvalidCell { error, .. } => match error {},
+        GridParseError::RaggedRow { .. } => {
+            unreachable!("ragged rows are only reported in strict mode")
+        }
+    }
+}
+
+fn parse_row<T, F, E>(line: &str, y: usize, f: &mut F) -> Result<Vec<T>, GridParseError<E>>
+where
+    F: FnMut(char, Point) -> Result<T, E>,
+{
+    line.chars()
+        .enumerate()
+        .map(|(x, ch)| {
+            let point = Point::new(x as i64, y as i64);
+            f(ch, point).map_err(|error| GridParseError::InvalidCell { point, ch, error })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn digit(ch: char, _: Point) -> Result<u32, String> {
+        ch.to_digit(10).ok_or(format!("'{ch}' is not a digit"))
+    }
+
+    #[test]
+    fn test_parse_with() {
+        // Act
+        let actual = Grid::parse_with("12\n3\n".lines(), digit);
+
+        // Assert
+        assert_eq!(actual, Ok(Grid::from_rows(vec![vec![1, 2], vec![3]])));
+    }
+
+    #[test]
+    fn test_parse_with_passes_position() {
+        // Act
+        let actual: Result<Grid<Point>, GridParseError<Infallible>> =
+            Grid::parse_with("ab\ncd\n".lines(), |_, point| Ok(point));
+
+        // Assert
+        assert_eq!(actual.unwrap().at(1, 1), Some(&Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_parse_with_invalid_cell() {
+        // Act
+        let actual = Grid::parse_with("12\n3x\n".lines(), digit);
+
+        // Assert
+        assert_eq!(
+            actual,
+            Err(GridParseError::InvalidCell {
+                point: Point::new(1, 1),
+                ch: 'x',
+                error: "'x' is not a digit".to_string(),
+            })
+        );
+    }
+
+    #[rstest]
+    #[case(RowPolicy::Ragged, vec![vec![1, 2, 3], vec![4]])]
+    #[case(RowPolicy::Pad(0), vec![vec![1, 2, 3], vec![4, 0, 0]])]
+    fn test_parse_with_policy(#[case] policy: RowPolicy<u32>, #[case] expected: Vec<Vec<u32>>) {
+        // Act
+        let actual = Grid::parse_with_policy("123\n4\n".lines(), policy, digit);
+
+        // Assert
+        assert_eq!(actual, Ok(Grid::from_rows(expected)));
+    }
+
+    #[test]
+    fn test_parse_with_policy_strict() {
+        // Act
+        let actual = Grid::parse_with_policy("12\n34\n567\n".lines(), RowPolicy::Strict, digit);
+
+        // Assert
+        assert_eq!(
+            actual,
+            Err(GridParseError::RaggedRow {
+                y: 2,
+                expected_width: 2,
+                actual_width: 3,
+            })
+        );
+        assert_eq!(
+            actual.unwrap_err().to_string(),
+            "row 2 has width 3, expected 2"
+        );
+    }
+
+    #[test]
+    fn test_parse_padded() {
+        // Act
+        let actual: Grid<char> = Grid::parse_padded("1 2\n1\n".lines(), ' ');
+
+        // Assert
+        assert_eq!(actual, Grid::parse("1 2\n1  \n".lines()));
+        assert_eq!(actual.column(2), vec![&'2', &' ']);
+    }
+
+    #[test]
+    fn test_parse_with_specials() {
+        // Act
+        let (grid, specials): (Grid<char>, _) =
+            Grid::parse_with_specials(".S.\nE.S\n".lines(), &['S', 'E'], '.');
+
+        // Assert
+        assert_eq!(grid, Grid::parse("...\n...\n".lines()));
+        assert_eq!(specials[&'S'], vec![Point::new(1, 0), Point::new(2, 1)]);
+        assert_eq!(specials[&'E'], vec![Point::new(0, 1)]);
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{Grid, Point};
+
+/// Writes every row on its own line, so for single character cells the output parses back into
+/// the same grid with `Grid::parse`.
+impl<T> fmt::Display for Grid<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum AnsiColor {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl AnsiColor {
+    fn code(&self) -> u8 {
+        match self {
+            AnsiColor::Red => 31,
+            AnsiColor::Green => 32,
+            AnsiColor::Yellow => 33,
+            AnsiColor::Blue => 34,
+            AnsiColor::Magenta => 35,
+            AnsiColor::Cyan => 36,
+        }
+    }
+}
+
+/// Points to highlight when rendering a grid. When a point is highlighted more than once,
+/// the last highlight wins.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Overlay {
+    colors: HashMap<Point, AnsiColor>,
+}
+
+impl Overlay {
+    pub fn new() -> Self {
+        Overlay {
+            colors: HashMap::new(),
+        }
+    }
+
+    pub fn highlight<I>(mut self, points: I, color: AnsiColor) -> Self
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        for p in points {
+            self.colors.insert(p, color);
+        }
+        self
+    }
+
+    /// Highlights a path, with its start and end point in their own colour so the direction shows.
+    pub fn highlight_path(
+        self,
+        path: &[Point],
+        color: AnsiColor,
+        endpoints_color: AnsiColor,
+    ) -> Self {
+        let endpoints: Vec<Point> = path
+            .first()
+            .into_iter()
+            .chain(path.last())
+            .copied()
+            .collect();
+        self.highlight(path.iter().copied(), color)
+            .highlight(endpoints, endpoints_color)
+    }
+
+    pub fn color_at(&self, p: &Point) -> Option<AnsiColor> {
+        self.colors.get(p).copied()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl<T> Grid<T>
+where
+    T: fmt::Display,
+{
+    /// Renders the grid like `Display` does, with the cells in `overlay` coloured using ANSI codes.
+    pub fn render_with(&self, overlay: &Overlay) -> String {
+        let mut output = String::new();
+        for (y, row) in self.rows().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                match overlay.color_at(&Point::new(x as i64, y as i64)) {
+                    Some(color) => output.push_str(&format!("\x1b[{}m{cell}\x1b[0m", color.code())),
+                    None => output.push_str(&cell.to_string()),
+                }
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+impl<T> Grid<T> {
+    /// Encodes the grid as a binary PPM (P6) image, where every cell becomes a `scale` x `scale`
+    /// block of pixels in the colour returned by `color_of`.
+    pub fn to_ppm<F>(&self, scale: usize, color_of: F) -> Vec<u8>
+    where
+        F: Fn(&T) -> Rgb,
+    {
+        let grid_width = self.width();
+        let width = grid_width * scale;
+        let height = self.height() * scale;
+        let mut image = format!("P6\n{width} {height}\n255\n").into_bytes();
+        image.reserve(width * height * 3);
+
+        for row in self.rows() {
+            let mut pixel_row: Vec<u8> = Vec::with_capacity(width * 3);
+            for x in 0..grid_width {
+                // Short rows are padded with black.
+                let Rgb(r, g, b) = row.get(x).map_or(Rgb(0, 0, 0), &color_of);
+                for _ in 0..scale {
+                    pixel_row.extend_from_slice(&[r, g, b]);
+                }
+            }
+            for _ in 0..scale {
+                image.extend_from_slice(&pixel_row);
+            }
+        }
+
+        image
+    }
+
+    /// Writes the grid as a PPM image to `path`, see [`Grid::to_ppm`].
+    pub fn write_ppm<F>(&self, path: &Path, scale: usize, color_of: F) -> io::Result<()>
+    where
+        F: Fn(&T) -> Rgb,
+    {
+        fs::write(path, self.to_ppm(scale, color_of))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_round_trips() {
+        // Arrange
+        let input = "..@\n@.@\n";
+        let grid: Grid<char> = Grid::parse(input.lines());
+
+        // Act
+        let actual = grid.to_string();
+
+        // Assert
+        assert_eq!(actual, input);
+        assert_eq!(Grid::parse(actual.lines()), grid);
+    }
+
+    #[test]
+    fn test_render_with() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("ab\ncd\n".lines());
+        let overlay = Overlay::new()
+            .highlight([Point::new(0, 0), Point::new(1, 1)], AnsiColor::Red)
+            .highlight([Point::new(1, 1)], AnsiColor::Green);
+
+        // Act
+        let actual = grid.render_with(&overlay);
+
+        // Assert
+        assert_eq!(actual, "\x1b[31ma\x1b[0mb\nc\x1b[32md\x1b[0m\n");
+    }
+
+    #[test]
+    fn test_highlight_path() {
+        // Arrange
+        let path = vec![Point::new(0, 0), Point::new(1, 0), Point::new(2, 0)];
+
+        // Act
+        let actual = Overlay::new().highlight_path(&path, AnsiColor::Blue, AnsiColor::Yellow);
+
+        // Assert
+        assert_eq!(actual.color_at(&Point::new(0, 0)), Some(AnsiColor::Yellow));
+        assert_eq!(actual.color_at(&Point::new(1, 0)), Some(AnsiColor::Blue));
+        assert_eq!(actual.color_at(&Point::new(2, 0)), Some(AnsiColor::Yellow));
+        assert_eq!(actual.color_at(&Point::new(0, 1)), None);
+    }
+
+    #[test]
+    fn test_to_ppm() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("#.\n".lines());
+
+        // Act
+        let actual = grid.to_ppm(2, |&ch| {
+            if ch == '#' {
+                Rgb(255, 255, 255)
+            } else {
+                Rgb(0, 0, 0)
+            }
+        });
+
+        // Assert
+        let mut expected = b"P6\n4 2\n255\n".to_vec();
+        let row = [255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0];
+        expected.extend_from_slice(&row);
+        expected.extend_from_slice(&row);
+        assert_eq!(actual, expected);
+    }
+}
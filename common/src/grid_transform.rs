@@ -0,0 +1,287 @@
+use crate::{Grid, Point, Rect};
+
+/// A geometric transformation of a grid. Every transformation maps each cell to exactly one cell of
+/// the result, so a value found at `p` in the original is at `map_point(p, width, height)` afterwards.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum GridTransform {
+    /// Rotate a quarter turn clockwise: `(x, y)` becomes `(height - 1 - y, x)`.
+    RotateCw,
+    /// Rotate a quarter turn counterclockwise: `(x, y)` becomes `(y, width - 1 - x)`.
+    RotateCcw,
+    /// Rotate half a turn: `(x, y)` becomes `(width - 1 - x, height - 1 - y)`.
+    Rotate180,
+    /// Mirror left to right: `(x, y)` becomes `(width - 1 - x, y)`.
+    FlipH,
+    /// Mirror top to bottom: `(x, y)` becomes `(x, height - 1 - y)`.
+    FlipV,
+    /// Mirror along the main diagonal: `(x, y)` becomes `(y, x)`.
+    Transpose,
+}
+
+impl GridTransform {
+    /// Maps a point of a grid with the given dimensions to its position in the transformed grid.
+    pub fn map_point(&self, p: &Point, width: usize, height: usize) -> Point {
+        let max_x = width as i64 - 1;
+        let max_y = height as i64 - 1;
+        match self {
+            GridTransform::RotateCw => Point::new(max_y - p.y, p.x),
+            GridTransform::RotateCcw => Point::new(p.y, max_x - p.x),
+            GridTransform::Rotate180 => Point::new(max_x - p.x, max_y - p.y),
+            GridTransform::FlipH => Point::new(max_x - p.x, p.y),
+            GridTransform::FlipV => Point::new(p.x, max_y - p.y),
+            GridTransform::Transpose => Point::new(p.y, p.x),
+        }
+    }
+
+    /// The dimensions of the transformed grid.
+    pub fn dimensions(&self, width: usize, height: usize) -> (usize, usize) {
+        match self {
+            GridTransform::RotateCw | GridTransform::RotateCcw | GridTransform::Transpose => {
+                (height, width)
+            }
+            _ => (width, height),
+        }
+    }
+
+    /// The transformation that undoes this one.
+    pub fn inverse(&self) -> GridTransform {
+        match self {
+            GridTransform::RotateCw => GridTransform::RotateCcw,
+            GridTransform::RotateCcw => GridTransform::RotateCw,
+            other => *other,
+        }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Clone,
+{
+    /// Applies `transform`, or returns None if the rows differ in width, since a ragged grid has
+    /// cells that would end up outside the rows of the result.
+    pub fn transform(&self, transform: GridTransform) -> Option<Grid<T>> {
+        let (width, height) = transform.dimensions(self.width(), self.height());
+        let inverse = transform.inverse();
+        Grid::try_from_fn(width, height, |p| {
+            let original = inverse.map_point(&p, width, height);
+            self.at_point(&original).cloned()
+        })
+    }
+
+    pub fn rotate_cw(&self) -> Option<Grid<T>> {
+        self.transform(GridTransform::RotateCw)
+    }
+
+    pub fn rotate_ccw(&self) -> Option<Grid<T>> {
+        self.transform(GridTransform::RotateCcw)
+    }
+
+    pub fn flip_h(&self) -> Option<Grid<T>> {
+        self.transform(GridTransform::FlipH)
+    }
+
+    pub fn flip_v(&self) -> Option<Grid<T>> {
+        self.transform(GridTransform::FlipV)
+    }
+
+    pub fn transpose(&self) -> Option<Grid<T>> {
+        self.transform(GridTransform::Transpose)
+    }
+
+    /// The cells within `rect` (bounds included), where `(rect.left, rect.top)` becomes `(0, 0)`.
+    /// Returns None if `rect` doesn't lie completely within the grid, including when a row inside
+    /// `rect` is too short.
+    pub fn sub_grid(&self, rect: &Rect) -> Option<Grid<T>> {
+        let width = rect.width() as usize;
+        let height = rect.height() as usize;
+        Grid::try_from_fn(width, height, |p| {
+            self.at_point(&p.translate(rect.left, rect.top)).cloned()
+        })
+    }
+
+    /// Repeats the grid `nx` times horizontally and `ny` times vertically, or returns None if the
+    /// rows differ in width. The cell at `(x, y)` of tile `(i, j)` ends up at
+    /// `(x + i * width, y + j * height)`.
+    pub fn tile(&self, nx: usize, ny: usize) -> Option<Grid<T>> {
+        let width = self.width();
+        let height = self.height();
+        Grid::try_from_fn(width * nx, height * ny, |p| {
+            self.at(p.x as usize % width, p.y as usize % height)
+                .cloned()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef\n".lines())
+    }
+
+    #[test]
+    fn test_rotate_cw() {
+        // Act
+        let actual = sample().rotate_cw().unwrap();
+
+        // Assert
+        assert_eq!(actual, Grid::parse("da\neb\nfc\n".lines()));
+    }
+
+    #[test]
+    fn test_rotate_ccw() {
+        // Act
+        let actual = sample().rotate_ccw().unwrap();
+
+        // Assert
+        assert_eq!(actual, Grid::parse("cf\nbe\nad\n".lines()));
+    }
+
+    #[test]
+    fn test_flip_h() {
+        // Act
+        let actual = sample().flip_h().unwrap();
+
+        // Assert
+        assert_eq!(actual, Grid::parse("cba\nfed\n".lines()));
+    }
+
+    #[test]
+    fn test_flip_v() {
+        // Act
+        let actual = sample().flip_v().unwrap();
+
+        // Assert
+        assert_eq!(actual, Grid::parse("def\nabc\n".lines()));
+    }
+
+    #[test]
+    fn test_transpose() {
+        // Act
+        let actual = sample().transpose().unwrap();
+
+        // Assert
+        assert_eq!(actual, Grid::parse("ad\nbe\ncf\n".lines()));
+    }
+
+    #[test]
+    fn test_four_rotations_are_identity() {
+        // Act
+        let actual = sample()
+            .rotate_cw()
+            .and_then(|grid| grid.rotate_cw())
+            .and_then(|grid| grid.rotate_cw())
+            .and_then(|grid| grid.rotate_cw());
+
+        // Assert
+        assert_eq!(actual, Some(sample()));
+    }
+
+    #[rstest]
+    #[case(GridTransform::RotateCw)]
+    #[case(GridTransform::RotateCcw)]
+    #[case(GridTransform::Rotate180)]
+    #[case(GridTransform::FlipH)]
+    #[case(GridTransform::FlipV)]
+    #[case(GridTransform::Transpose)]
+    fn test_map_point_is_consistent_with_transform(#[case] transform: GridTransform) {
+        // Arrange
+        let grid = sample();
+
+        // Act
+        let transformed = grid.transform(transform).unwrap();
+
+        // Assert
+        for (p, value) in grid.iter() {
+            let mapped = transform.map_point(&p, grid.width(), grid.height());
+            assert_eq!(transformed.at_point(&mapped), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_sub_grid() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abcd\nefgh\nijkl\n".lines());
+        let rect = Rect::new(&Point::new(1, 1), &Point::new(2, 2));
+
+        // Act
+        let actual = grid.sub_grid(&rect);
+
+        // Assert
+        assert_eq!(actual, Some(Grid::parse("fg\njk\n".lines())));
+    }
+
+    #[test]
+    fn test_sub_grid_out_of_bounds() {
+        // Arrange
+        let rect = Rect::new(&Point::new(1, 0), &Point::new(3, 1));
+
+        // Act
+        let actual = sample().sub_grid(&rect);
+
+        // Assert
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn test_tile() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("ab\n".lines());
+
+        // Act
+        let actual = grid.tile(2, 2);
+
+        // Assert
+        assert_eq!(actual, Some(Grid::parse("abab\nabab\n".lines())));
+    }
+
+    #[rstest]
+    #[case(GridTransform::RotateCw)]
+    #[case(GridTransform::FlipH)]
+    #[case(GridTransform::Transpose)]
+    fn test_transform_ragged_grid(#[case] transform: GridTransform) {
+        // Arrange
+        let grid: Grid<char> = Grid::parse(
+            "abc
+d
+"
+            .lines(),
+        );
+
+        // Act
+        let actual = grid.transform(transform);
+
+        // Assert
+        assert_eq!(actual, None);
+    }
+
+    #[rstest]
+    #[case(Rect::new(&Point::new(0, 0), &Point::new(1, 2)), None)]
+    #[case(Rect::new(&Point::new(1, 0), &Point::new(2, 0)), Some(Grid::parse("bc\n".lines())))]
+    #[case(Rect::new(&Point::new(0, 1), &Point::new(0, 2)), Some(Grid::parse("d\ne\n".lines())))]
+    fn test_sub_grid_ragged_grid(#[case] rect: Rect, #[case] expected: Option<Grid<char>>) {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("abc\nd\nef\n".lines());
+
+        // Act
+        let actual = grid.sub_grid(&rect);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_tile_ragged_grid() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse("ab\nc\n".lines());
+
+        // Act
+        let actual = grid.tile(2, 1);
+
+        // Assert
+        assert_eq!(actual, None);
+    }
+}
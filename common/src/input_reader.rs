@@ -1,6 +1,47 @@
+use std::env;
+use std::fmt;
 use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
 use std::str::Lines;
 
+/// Environment variable that overrides the directory containing the `<day>.txt` input files.
+pub const INPUT_DIR_ENV_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file does not exist.
+    MissingFile(PathBuf),
+    /// The input contains nothing but whitespace. The string describes where the input came from.
+    Empty(String),
+    /// The input is not valid UTF-8. The string describes where the input came from.
+    NotUtf8(String),
+    /// Any other I/O error while reading the input.
+    Io(String, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::MissingFile(path) => write!(f, "input file {} not found", path.display()),
+            InputError::Empty(source) => write!(f, "input from {source} is empty"),
+            InputError::NotUtf8(source) => write!(f, "input from {source} is not valid UTF-8"),
+            InputError::Io(source, error) => {
+                write!(f, "failed to read input from {source}: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
 /// Because we need something to be the owner of the strings that is read from the file,
 /// we create a struct that will hold the string and offer a function to create an iterator on it.
 pub struct InputReader {
@@ -8,10 +49,45 @@ pub struct InputReader {
 }
 
 impl InputReader {
-    pub fn new(day_nr: u8) -> Self {
-        let path = format!("../input/{:02}.txt", day_nr);
-        let input = fs::read_to_string(&path).unwrap();
-        InputReader { input }
+    /// Reads the input of the given day from the default input directory, see [`resolve_input_dir`].
+    pub fn new(day_nr: u8) -> Result<Self, InputError> {
+        Self::from_dir(&resolve_input_dir(None), day_nr)
+    }
+
+    /// Reads the input of the given day from `<dir>/<day>.txt`, with the day zero-padded to 2 digits.
+    pub fn from_dir(dir: &Path, day_nr: u8) -> Result<Self, InputError> {
+        Self::from_file(&dir.join(format!("{:02}.txt", day_nr)))
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, InputError> {
+        let bytes = fs::read(path).map_err(|error| match error.kind() {
+            io::ErrorKind::NotFound => InputError::MissingFile(path.to_path_buf()),
+            _ => InputError::Io(path.display().to_string(), error),
+        })?;
+        Self::from_bytes(bytes, &path.display().to_string())
+    }
+
+    pub fn from_stdin() -> Result<Self, InputError> {
+        Self::from_reader(io::stdin().lock(), "stdin")
+    }
+
+    /// Reads the complete input from `reader`. `source` describes the reader in error messages.
+    pub fn from_reader<R: Read>(mut reader: R, source: &str) -> Result<Self, InputError> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .map_err(|error| InputError::Io(source.to_string(), error))?;
+        Self::from_bytes(bytes, source)
+    }
+
+    fn from_bytes(bytes: Vec<u8>, source: &str) -> Result<Self, InputError> {
+        let input =
+            String::from_utf8(bytes).map_err(|_| InputError::NotUtf8(source.to_string()))?;
+        if input.trim().is_empty() {
+            return Err(InputError::Empty(source.to_string()));
+        }
+
+        Ok(InputReader { input })
     }
 
     pub fn lines<'a>(&'a self) -> Lines<'a> {
@@ -22,3 +98,131 @@ impl InputReader {
         self.input.to_string()
     }
 }
+
+/// Determines the directory containing the input files, in order of preference:
+/// 1. `explicit_dir`, typically passed on the command line,
+/// 2. the directory in the `AOC_INPUT_DIR` environment variable,
+/// 3. the `input` directory in the workspace root containing the current directory,
+/// 4. `input` relative to the current directory.
+pub fn resolve_input_dir(explicit_dir: Option<&Path>) -> PathBuf {
+    if let Some(dir) = explicit_dir {
+        return dir.to_path_buf();
+    }
+
+    if let Some(dir) = env::var_os(INPUT_DIR_ENV_VAR) {
+        return PathBuf::from(dir);
+    }
+
+    env::current_dir()
+        .ok()
+        .and_then(|current_dir| find_workspace_root(&current_dir))
+        .map_or_else(|| PathBuf::from("input"), |root| root.join("input"))
+}
+
+/// Walks up from `start` to the first directory with a Cargo.toml that defines a workspace.
+fn find_workspace_root(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+        })
+        .map(Path::to_path_buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn write_temp_file(name: &str, contents: &[u8]) -> PathBuf {
+        let path =
+            env::temp_dir().join(format!("common-input-reader-{}-{name}", std::process::id()));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_from_file() {
+        // Arrange
+        let path = write_temp_file("valid.txt", b"abc\ndef\n");
+
+        // Act
+        let actual = InputReader::from_file(&path).unwrap();
+
+        // Assert
+        assert_eq!(actual.lines().collect::<Vec<&str>>(), vec!["abc", "def"]);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_from_file_missing() {
+        // Arrange
+        let path = env::temp_dir().join("common-input-reader-does-not-exist.txt");
+
+        // Act
+        let actual = InputReader::from_file(&path);
+
+        // Assert
+        assert!(matches!(actual, Err(InputError::MissingFile(missing)) if missing == path));
+    }
+
+    #[test]
+    fn test_from_file_empty() {
+        // Arrange
+        let path = write_temp_file("empty.txt", b" \n\n");
+
+        // Act
+        let actual = InputReader::from_file(&path);
+
+        // Assert
+        assert!(matches!(actual, Err(InputError::Empty(_))));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_from_reader_not_utf8() {
+        // Act
+        let actual = InputReader::from_reader(Cursor::new(vec![0x61, 0xff, 0x62]), "test");
+
+        // Assert
+        assert!(matches!(actual, Err(InputError::NotUtf8(source)) if source == "test"));
+    }
+
+    #[test]
+    fn test_from_dir_pads_day() {
+        // Arrange
+        let dir = env::temp_dir().join(format!("common-input-reader-dir-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("07.txt"), "S\n").unwrap();
+
+        // Act
+        let actual = InputReader::from_dir(&dir, 7).unwrap();
+
+        // Assert
+        assert_eq!(actual.input(), "S\n");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_input_dir_prefers_explicit_dir() {
+        // Act
+        let actual = resolve_input_dir(Some(Path::new("/some/dir")));
+
+        // Assert
+        assert_eq!(actual, PathBuf::from("/some/dir"));
+    }
+
+    #[test]
+    fn test_find_workspace_root() {
+        // Arrange
+        let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        // Act
+        let actual = find_workspace_root(crate_dir);
+
+        // Assert
+        assert_eq!(actual, crate_dir.parent().map(Path::to_path_buf));
+    }
+}
@@ -0,0 +1,210 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Sub};
+
+use crate::Range;
+
+/// An immutable index over possibly overlapping ranges, answering which ranges contain a value or
+/// overlap another range without scanning all of them.
+///
+/// The ranges are sorted by start and form an implicit balanced search tree, where every node also
+/// stores the largest end in its subtree so subtrees that end too early are skipped.
+#[derive(Clone)]
+pub struct IntervalIndex<T>
+where
+    T: PartialOrd + Copy,
+{
+    ranges: Vec<Range<T>>,
+    /// `max_ends[i]` is the largest end of the subtree with `ranges[i]` at its root.
+    max_ends: Vec<T>,
+}
+
+impl<T> IntervalIndex<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + Sub<Output = T> + Default,
+{
+    pub fn new(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.sort_by(|a, b| a.start().partial_cmp(&b.start()).unwrap_or(Ordering::Equal));
+        let mut max_ends: Vec<T> = ranges.iter().map(|range| range.end()).collect();
+        compute_max_ends(&ranges, &mut max_ends, 0, ranges.len());
+        IntervalIndex { ranges, max_ends }
+    }
+
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The ranges containing `x`, ordered by start.
+    pub fn containing(&self, x: T) -> Vec<Range<T>> {
+        self.overlapping(&Range::new(x, T::default()), |range| range.contains(x))
+    }
+
+    /// Checks if any range contains `x`, stopping at the first one found.
+    pub fn contains(&self, x: T) -> bool {
+        let mut found = false;
+        self.visit(0, self.ranges.len(), x, x, &mut |range| {
+            found = range.contains(x);
+            found
+        });
+        found
+    }
+
+    /// The ranges sharing at least one value with `query`, ordered by start.
+    pub fn overlaps(&self, query: &Range<T>) -> Vec<Range<T>> {
+        self.overlapping(query, |range| {
+            range.length() > T::default() && range.overlaps(query)
+        })
+    }
+
+    fn overlapping<F>(&self, query: &Range<T>, matches: F) -> Vec<Range<T>>
+    where
+        F: Fn(&Range<T>) -> bool,
+    {
+        let mut result = vec![];
+        // A value is matched by ranges starting at or before it, so an empty query still finds its
+        // containing ranges.
+        let last_start = if query.length() > T::default() {
+            query.end()
+        } else {
+            query.start()
+        };
+        self.visit(
+            0,
+            self.ranges.len(),
+            query.start(),
+            last_start,
+            &mut |range| {
+                if matches(range) {
+                    result.push(*range);
+                }
+                false
+            },
+        );
+        result
+    }
+
+    /// Visits, in order of start, every range that ends after `min_end` and starts at or before
+    /// `max_start`, until `visitor` returns true. Returns whether the visit was stopped.
+    fn visit<F>(&self, from: usize, to: usize, min_end: T, max_start: T, visitor: &mut F) -> bool
+    where
+        F: FnMut(&Range<T>) -> bool,
+    {
+        if from >= to {
+            return false;
+        }
+        let mid = (from + to) / 2;
+        if self.max_ends[mid] <= min_end {
+            return false;
+        }
+
+        if self.visit(from, mid, min_end, max_start, visitor) {
+            return true;
+        }
+        if self.ranges[mid].start() > max_start {
+            // Everything to the right starts even later.
+            return false;
+        }
+        visitor(&self.ranges[mid]) || self.visit(mid + 1, to, min_end, max_start, visitor)
+    }
+}
+
+fn compute_max_ends<T>(ranges: &[Range<T>], max_ends: &mut [T], from: usize, to: usize) -> Option<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + Sub<Output = T> + Default,
+{
+    if from >= to {
+        return None;
+    }
+    let mid = (from + to) / 2;
+    let mut max_end = ranges[mid].end();
+    let children = [
+        compute_max_ends(ranges, max_ends, from, mid),
+        compute_max_ends(ranges, max_ends, mid + 1, to),
+    ];
+    for child_end in children.into_iter().flatten() {
+        if child_end > max_end {
+            max_end = child_end;
+        }
+    }
+    max_ends[mid] = max_end;
+    Some(max_end)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn index() -> IntervalIndex<i64> {
+        IntervalIndex::new(vec![
+            Range::from_inclusive(12, 18),
+            Range::from_inclusive(3, 5),
+            Range::from_inclusive(16, 20),
+            Range::from_inclusive(10, 14),
+            Range::new(7, 0),
+        ])
+    }
+
+    #[rstest]
+    #[case(1, vec![])]
+    #[case(5, vec![Range::from_inclusive(3, 5)])]
+    #[case(7, vec![])]
+    #[case(11, vec![Range::from_inclusive(10, 14)])]
+    #[case(13, vec![Range::from_inclusive(10, 14), Range::from_inclusive(12, 18)])]
+    #[case(17, vec![Range::from_inclusive(12, 18), Range::from_inclusive(16, 20)])]
+    #[case(32, vec![])]
+    fn test_containing(#[case] x: i64, #[case] expected: Vec<Range<i64>>) {
+        // Act
+        let actual = index().containing(x);
+
+        // Assert
+        assert_eq!(actual, expected);
+        assert_eq!(index().contains(x), !expected.is_empty());
+    }
+
+    #[rstest]
+    #[case(Range::from_inclusive(5, 10), vec![Range::from_inclusive(3, 5), Range::from_inclusive(10, 14)])]
+    #[case(Range::from_inclusive(6, 9), vec![])]
+    #[case(Range::from_inclusive(19, 30), vec![Range::from_inclusive(16, 20)])]
+    fn test_overlaps(#[case] query: Range<i64>, #[case] expected: Vec<Range<i64>>) {
+        // Act
+        let actual = index().overlaps(&query);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_matches_linear_scan() {
+        // Arrange
+        let ranges: Vec<Range<i64>> = (0..200)
+            .map(|i| Range::new((i * 37) % 101, (i * 13) % 17))
+            .collect();
+        let index = IntervalIndex::new(ranges.clone());
+
+        // Act & Assert
+        for x in -1..120 {
+            let mut expected: Vec<Range<i64>> =
+                ranges.iter().copied().filter(|r| r.contains(x)).collect();
+            let mut actual = index.containing(x);
+            actual.sort_by_key(|r| (r.start(), r.length()));
+            expected.sort_by_key(|r| (r.start(), r.length()));
+            assert_eq!(actual, expected, "x = {x}");
+        }
+    }
+
+    #[test]
+    fn test_empty_index() {
+        // Arrange
+        let index: IntervalIndex<i64> = IntervalIndex::new(vec![]);
+
+        // Act & Assert
+        assert!(index.is_empty());
+        assert!(!index.contains(0));
+        assert_eq!(index.containing(0), vec![]);
+    }
+}
@@ -0,0 +1,500 @@
+use std::collections::BinaryHeap;
+
+use crate::{Coordinate, Cuboid, Point, Point3d, Rect};
+
+/// An immutable k-d tree over `Point`s, `Point3d`s or any other [`Coordinate`].
+///
+/// The points form an implicit balanced tree: every subslice has its median along the splitting
+/// axis in the middle, with smaller values before it and larger values after it. Query results are
+/// `(index, point)` pairs, where `index` is the position of the point in the `Vec` the tree was
+/// built from. Ties in distance are broken by the smallest index.
+#[derive(Debug, Clone)]
+pub struct KdTree<C> {
+    nodes: Vec<(C, usize)>,
+    dimensions: usize,
+}
+
+impl<C> KdTree<C>
+where
+    C: Coordinate,
+{
+    /// Builds the tree in `O(n log n)` time.
+    pub fn new(points: Vec<C>) -> Self {
+        let dimensions = points.first().map_or(0, |p| p.components().count());
+        let mut nodes: Vec<(C, usize)> = points
+            .into_iter()
+            .enumerate()
+            .map(|(index, p)| (p, index))
+            .collect();
+        build(&mut nodes, 0, dimensions);
+        KdTree { nodes, dimensions }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The closest point to `target`, or None if the tree is empty.
+    pub fn nearest(&self, target: &C) -> Option<(usize, C)> {
+        self.k_nearest(target, 1).pop()
+    }
+
+    /// The `k` closest points to `target`, closest first.
+    pub fn k_nearest(&self, target: &C, k: usize) -> Vec<(usize, C)> {
+        if k == 0 {
+            return vec![];
+        }
+        let mut best: BinaryHeap<(i64, usize, usize)> = BinaryHeap::new();
+        self.visit_nearest(0, self.nodes.len(), 0, target, k, &mut best);
+
+        best.into_sorted_vec()
+            .into_iter()
+            .map(|(_, index, position)| (index, self.nodes[position].0))
+            .collect()
+    }
+
+    /// The points within euclidean distance `sqrt(squared_radius)` of `center`, boundary
+    /// included, ordered by distance.
+    pub fn within_squared_distance(&self, center: &C, squared_radius: i64) -> Vec<(usize, C)> {
+        let axis_offset = |axis: usize, split: i64| axis_value(center, axis) - split;
+        let mut result = self.search(
+            &|axis, split| {
+                let offset = axis_offset(axis, split);
+                offset <= 0 || offset * offset <= squared_radius
+            },
+            &|axis, split| {
+                let offset = axis_offset(axis, split);
+                offset >= 0 || offset * offset <= squared_radius
+            },
+            &|p| center.squared_distance(p) <= squared_radius,
+        );
+        result.sort_by_key(|(index, p)| (center.squared_distance(p), *index));
+        result
+    }
+
+    /// The points within manhattan distance `radius` of `center`, boundary included, ordered by
+    /// distance.
+    pub fn within_manhattan_distance(&self, center: &C, radius: u64) -> Vec<(usize, C)> {
+        let radius = radius as i64;
+        let mut result = self.search(
+            &|axis, split| split >= axis_value(center, axis) - radius,
+            &|axis, split| split <= axis_value(center, axis) + radius,
+            &|p| center.manhattan_distance(p) <= radius as u64,
+        );
+        result.sort_by_key(|(index, p)| (center.manhattan_distance(p), *index));
+        result
+    }
+
+    /// The points inside the box spanned by the corners `min` and `max`, both inclusive, ordered by
+    /// index.
+    pub fn within_box(&self, min: &C, max: &C) -> Vec<(usize, C)> {
+        let inside = |p: &C| {
+            p.components()
+                .zip(min.components().zip(max.components()))
+                .all(|(value, (low, high))| (low..=high).contains(&value))
+        };
+        let mut result = self.search(
+            &|axis, split| split >= axis_value(min, axis),
+            &|axis, split| split <= axis_value(max, axis),
+            &inside,
+        );
+        result.sort_by_key(|(index, _)| *index);
+        result
+    }
+
+    /// For every group, the shortest pair `(squared distance, a, b)` with `a < b` that connects a
+    /// point of the group to a point outside it, ties broken by the smallest `(a, b)`. `groups`
+    /// holds the group of every point by index, numbered below the number of points.
+    pub(crate) fn closest_to_other_groups(
+        &self,
+        groups: &[usize],
+    ) -> Vec<Option<(i64, usize, usize)>> {
+        let mut subtree_groups = vec![None; self.nodes.len()];
+        self.label_subtrees(0, self.nodes.len(), groups, &mut subtree_groups);
+
+        let mut closest = vec![None; self.nodes.len()];
+        for &(target, index) in &self.nodes {
+            let group = groups[index];
+            let query = GroupQuery {
+                target,
+                index,
+                group,
+                groups,
+                subtree_groups: &subtree_groups,
+            };
+            self.visit_other_groups(0, self.nodes.len(), 0, &query, &mut closest[group]);
+        }
+        closest
+    }
+
+    /// Stores in `subtree_groups` at the root of every subtree the group all its points share, if
+    /// any, and returns it.
+    fn label_subtrees(
+        &self,
+        from: usize,
+        to: usize,
+        groups: &[usize],
+        subtree_groups: &mut [Option<usize>],
+    ) -> Option<usize> {
+        let mid = (from + to) / 2;
+        let group = groups[self.nodes[mid].1];
+        let lower = (from < mid).then(|| self.label_subtrees(from, mid, groups, subtree_groups));
+        let upper =
+            (mid + 1 < to).then(|| self.label_subtrees(mid + 1, to, groups, subtree_groups));
+        let shared = [lower, upper]
+            .into_iter()
+            .flatten()
+            .all(|child| child == Some(group));
+        subtree_groups[mid] = shared.then_some(group);
+        subtree_groups[mid]
+    }
+
+    /// Improves `best` with the pairs between the query point and the points of other groups,
+    /// skipping subtrees that only hold points of its own group.
+    fn visit_other_groups(
+        &self,
+        from: usize,
+        to: usize,
+        depth: usize,
+        query: &GroupQuery<C>,
+        best: &mut Option<(i64, usize, usize)>,
+    ) {
+        if from >= to {
+            return;
+        }
+        let mid = (from + to) / 2;
+        if query.subtree_groups[mid] == Some(query.group) {
+            return;
+        }
+        let (p, index) = self.nodes[mid];
+        if query.groups[index] != query.group {
+            let pair = (
+                query.target.squared_distance(&p),
+                index.min(query.index),
+                index.max(query.index),
+            );
+            if best.is_none_or(|closest| pair < closest) {
+                *best = Some(pair);
+            }
+        }
+
+        let axis = depth % self.dimensions;
+        let offset = axis_value(&query.target, axis) - axis_value(&p, axis);
+        let (near, far) = if offset < 0 {
+            ((from, mid), (mid + 1, to))
+        } else {
+            ((mid + 1, to), (from, mid))
+        };
+        self.visit_other_groups(near.0, near.1, depth + 1, query, best);
+        if best.is_none_or(|(closest, _, _)| offset * offset <= closest) {
+            self.visit_other_groups(far.0, far.1, depth + 1, query, best);
+        }
+    }
+
+    /// Collects the points satisfying `matches`, only entering the part of a subtree before its
+    /// split value if `reaches_lower(axis, split)` and the part after it if `reaches_upper`.
+    fn search<L, U, M>(&self, reaches_lower: &L, reaches_upper: &U, matches: &M) -> Vec<(usize, C)>
+    where
+        L: Fn(usize, i64) -> bool,
+        U: Fn(usize, i64) -> bool,
+        M: Fn(&C) -> bool,
+    {
+        let mut result = vec![];
+        let mut stack = vec![(0, self.nodes.len(), 0)];
+        while let Some((from, to, depth)) = stack.pop() {
+            if from >= to {
+                continue;
+            }
+            let mid = (from + to) / 2;
+            let (p, index) = self.nodes[mid];
+            if matches(&p) {
+                result.push((index, p));
+            }
+            let axis = depth % self.dimensions;
+            let split = axis_value(&p, axis);
+            if reaches_lower(axis, split) {
+                stack.push((from, mid, depth + 1));
+            }
+            if reaches_upper(axis, split) {
+                stack.push((mid + 1, to, depth + 1));
+            }
+        }
+        result
+    }
+
+    /// Keeps the `k` best `(squared distance, index, position)` entries seen so far in `best`.
+    fn visit_nearest(
+        &self,
+        from: usize,
+        to: usize,
+        depth: usize,
+        target: &C,
+        k: usize,
+        best: &mut BinaryHeap<(i64, usize, usize)>,
+    ) {
+        if from >= to {
+            return;
+        }
+        let mid = (from + to) / 2;
+        let (p, index) = self.nodes[mid];
+        best.push((target.squared_distance(&p), index, mid));
+        if best.len() > k {
+            best.pop();
+        }
+
+        let axis = depth % self.dimensions;
+        let offset = axis_value(target, axis) - axis_value(&p, axis);
+        let (near, far) = if offset < 0 {
+            ((from, mid), (mid + 1, to))
+        } else {
+            ((mid + 1, to), (from, mid))
+        };
+        self.visit_nearest(near.0, near.1, depth + 1, target, k, best);
+        // Points on the far side are at least `offset` away along this axis.
+        if best.len() < k
+            || best
+                .peek()
+                .is_some_and(|&(worst, _, _)| offset * offset <= worst)
+        {
+            self.visit_nearest(far.0, far.1, depth + 1, target, k, best);
+        }
+    }
+}
+
+/// A point looking for its closest point in another group, see
+/// [`KdTree::closest_to_other_groups`].
+struct GroupQuery<'a, C> {
+    target: C,
+    index: usize,
+    group: usize,
+    groups: &'a [usize],
+    /// The group shared by all points of the subtree rooted at every node, if any.
+    subtree_groups: &'a [Option<usize>],
+}
+
+impl KdTree<Point> {
+    /// The points inside `rect`, ordered by index.
+    pub fn within_rect(&self, rect: &Rect) -> Vec<(usize, Point)> {
+        self.within_box(
+            &Point::new(rect.left, rect.top),
+            &Point::new(rect.right, rect.bottom),
+        )
+    }
+}
+
+impl KdTree<Point3d> {
+    /// The points inside `cuboid`, ordered by index.
+    pub fn within_cuboid(&self, cuboid: &Cuboid) -> Vec<(usize, Point3d)> {
+        self.within_box(&cuboid.min, &cuboid.max)
+    }
+}
+
+impl<C> From<Vec<C>> for KdTree<C>
+where
+    C: Coordinate,
+{
+    fn from(points: Vec<C>) -> Self {
+        KdTree::new(points)
+    }
+}
+
+impl<C> FromIterator<C> for KdTree<C>
+where
+    C: Coordinate,
+{
+    fn from_iter<I: IntoIterator<Item = C>>(iter: I) -> Self {
+        KdTree::new(iter.into_iter().collect())
+    }
+}
+
+fn axis_value<C: Coordinate>(p: &C, axis: usize) -> i64 {
+    p.components().nth(axis).unwrap()
+}
+
+/// Arranges `nodes` so the median along the splitting axis of `depth` sits in the middle, and
+/// recurses into both halves.
+fn build<C: Coordinate>(nodes: &mut [(C, usize)], depth: usize, dimensions: usize) {
+    if nodes.len() <= 1 {
+        return;
+    }
+    let axis = depth % dimensions;
+    let mid = nodes.len() / 2;
+    nodes.select_nth_unstable_by_key(mid, |(p, _)| axis_value(p, axis));
+    let (lower, upper) = nodes.split_at_mut(mid);
+    build(lower, depth + 1, dimensions);
+    build(&mut upper[1..], depth + 1, dimensions);
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// A 10 x 5 x 5 lattice with spacing 4 followed by a copy of its first 50 points, so many
+    /// points share a split value, lie at the same distance from a target or coincide.
+    fn cloud() -> Vec<Point3d> {
+        let lattice = (0..250).map(|i| Point3d::new(i % 10 * 4, i / 10 % 5 * 4, i / 50 * 4 - 8));
+        lattice.clone().chain(lattice.take(50)).collect()
+    }
+
+    fn brute_force<F>(points: &[Point3d], key: F) -> Vec<(usize, Point3d)>
+    where
+        F: Fn(&Point3d) -> i64,
+    {
+        let mut result: Vec<(usize, Point3d)> = points.iter().copied().enumerate().collect();
+        result.sort_by_key(|(index, p)| (key(p), *index));
+        result
+    }
+
+    #[rstest]
+    #[case(Point3d::new(0, 0, 0), 1)]
+    #[case(Point3d::new(20, 8, 4), 7)]
+    #[case(Point3d::new(-50, 100, 3), 12)]
+    #[case(Point3d::new(13, 3, -2), 300)]
+    fn test_k_nearest_matches_brute_force(#[case] target: Point3d, #[case] k: usize) {
+        // Arrange
+        let points = cloud();
+        let tree = KdTree::new(points.clone());
+
+        // Act
+        let actual = tree.k_nearest(&target, k);
+
+        // Assert
+        let expected: Vec<(usize, Point3d)> = brute_force(&points, |p| target.squared_distance(p))
+            .into_iter()
+            .take(k)
+            .collect();
+        assert_eq!(actual, expected);
+        assert_eq!(tree.nearest(&target), expected.first().copied());
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(7)]
+    fn test_closest_to_other_groups_matches_brute_force(#[case] nr_groups: usize) {
+        // Arrange
+        let points = cloud();
+        let groups: Vec<usize> = (0..points.len()).map(|i| i % nr_groups).collect();
+        let tree = KdTree::new(points.clone());
+
+        // Act
+        let actual = tree.closest_to_other_groups(&groups);
+
+        // Assert
+        let mut expected = vec![None; points.len()];
+        for (a, b) in (0..points.len()).flat_map(|a| (a + 1..points.len()).map(move |b| (a, b))) {
+            if groups[a] != groups[b] {
+                let pair = (points[a].squared_distance(&points[b]), a, b);
+                for group in [groups[a], groups[b]] {
+                    let closest: &mut Option<(i64, usize, usize)> = &mut expected[group];
+                    if closest.is_none_or(|closest| pair < closest) {
+                        *closest = Some(pair);
+                    }
+                }
+            }
+        }
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(Point3d::new(20, 8, 4), 0)]
+    #[case(Point3d::new(20, 8, 4), 30)]
+    #[case(Point3d::new(0, 0, -10), 150)]
+    fn test_within_squared_distance(#[case] center: Point3d, #[case] squared_radius: i64) {
+        // Arrange
+        let points = cloud();
+        let tree = KdTree::new(points.clone());
+
+        // Act
+        let actual = tree.within_squared_distance(&center, squared_radius);
+
+        // Assert
+        let expected: Vec<(usize, Point3d)> = brute_force(&points, |p| center.squared_distance(p))
+            .into_iter()
+            .filter(|(_, p)| center.squared_distance(p) <= squared_radius)
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(Point3d::new(20, 8, 4), 3)]
+    #[case(Point3d::new(0, 0, -10), 12)]
+    fn test_within_manhattan_distance(#[case] center: Point3d, #[case] radius: u64) {
+        // Arrange
+        let points = cloud();
+        let tree = KdTree::new(points.clone());
+
+        // Act
+        let actual = tree.within_manhattan_distance(&center, radius);
+
+        // Assert
+        let expected: Vec<(usize, Point3d)> =
+            brute_force(&points, |p| center.manhattan_distance(p) as i64)
+                .into_iter()
+                .filter(|(_, p)| center.manhattan_distance(p) <= radius)
+                .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_within_cuboid() {
+        // Arrange
+        let points = cloud();
+        let tree: KdTree<Point3d> = points.clone().into();
+        let cuboid = Cuboid::new(&Point3d::new(5, 2, -3), &Point3d::new(15, 9, 6));
+
+        // Act
+        let actual = tree.within_cuboid(&cuboid);
+
+        // Assert
+        let expected: Vec<(usize, Point3d)> = points
+            .into_iter()
+            .enumerate()
+            .filter(|(_, p)| cuboid.contains(p))
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_2d_queries() {
+        // Arrange
+        let tree: KdTree<Point> = [(1, 1), (4, 2), (2, 5), (7, 7), (3, 3)]
+            .into_iter()
+            .map(|(x, y)| Point::new(x, y))
+            .collect();
+
+        // Act & Assert
+        assert_eq!(tree.len(), 5);
+        assert_eq!(tree.nearest(&Point::new(3, 4)), Some((4, Point::new(3, 3))));
+        assert_eq!(
+            tree.within_rect(&Rect::new(&Point::new(0, 0), &Point::new(4, 3))),
+            vec![
+                (0, Point::new(1, 1)),
+                (1, Point::new(4, 2)),
+                (4, Point::new(3, 3))
+            ]
+        );
+        assert_eq!(
+            tree.within_manhattan_distance(&Point::new(3, 4), 2),
+            vec![(4, Point::new(3, 3)), (2, Point::new(2, 5))]
+        );
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        // Arrange
+        let tree: KdTree<Point> = KdTree::new(vec![]);
+
+        // Act & Assert
+        assert!(tree.is_empty());
+        assert_eq!(tree.nearest(&Point::new(0, 0)), None);
+        assert_eq!(tree.within_squared_distance(&Point::new(0, 0), 100), vec![]);
+    }
+}
@@ -1,26 +1,92 @@
 mod input_reader;
-pub use input_reader::InputReader;
+pub use input_reader::{resolve_input_dir, InputError, InputReader, INPUT_DIR_ENV_VAR};
 
 mod directed_graph;
-pub use directed_graph::DirectedGraph;
+pub use directed_graph::{Condensation, DirectedGraph, GraphError};
+
+mod disjoint_set;
+pub use disjoint_set::{DisjointSet, KeyedDisjointSet};
+
+mod graph;
+pub use graph::{Edge, Graph, ShortestPaths};
+
+mod search;
+pub use search::{astar, bfs, bfs_to, count_paths, dfs, dijkstra, dijkstra_to, SearchResult};
 
 mod grid;
 pub use grid::Grid;
 
+mod grid_parse;
+pub use grid_parse::{GridParseError, RowPolicy};
+
+mod grid_transform;
+pub use grid_transform::GridTransform;
+
+mod grid_render;
+pub use grid_render::{AnsiColor, Overlay, Rgb};
+
+mod direction;
+pub use direction::{Direction4, Direction8};
+
+mod coordinate;
+pub use coordinate::Coordinate;
+
 mod point;
 pub use point::Point;
 
 mod point3d;
 pub use point3d::Point3d;
 
+mod cuboid;
+pub use cuboid::{Axis, Cuboid};
+
+mod grid3d;
+pub use grid3d::Grid3d;
+
+mod kd_tree;
+pub use kd_tree::KdTree;
+
+mod spatial;
+pub use spatial::{
+    closest_pairs, minimum_spanning_tree, single_linkage_clusters, ClosestPairs, PointPair,
+};
+
 mod rect;
 pub use rect::Rect;
 
+mod polygon;
+pub use polygon::{Orientation, Polygon, RectilinearPolygon};
+
 mod string_utils;
 pub use string_utils::*;
 
 mod range;
 pub use range::Range;
 
+mod interval_index;
+pub use interval_index::IntervalIndex;
+
+mod range_set;
+pub use range_set::RangeSet;
+
 mod sequence_generator;
 pub use sequence_generator::SequenceGenerator;
+
+mod combinatorics;
+pub use combinatorics::{
+    CartesianProduct, Combinations, Compositions, GrayCode, Permutations, SubsetsByPopcount,
+};
+
+mod solution;
+pub use solution::{DynSolution, ParsedSolution, Solution};
+
+mod answers;
+pub use answers::{
+    AnswerStore, Verdict, VerificationReport, VerificationResult, ANSWERS_FILE_NAME,
+};
+
+mod benchmark;
+pub use benchmark::{
+    format_duration, time, BenchmarkHistory, Phase, TimingStats, BENCHMARK_FILE_NAME,
+    REGRESSION_THRESHOLD,
+};
@@ -1,3 +1,7 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::Coordinate;
+
 #[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
 pub struct Point {
     pub x: i64,
@@ -20,7 +24,19 @@ impl Point {
     }
 
     pub fn manhattan_distance(&self, other: &Point) -> u64 {
-        (self.x - other.x).unsigned_abs() + (self.y - other.y).unsigned_abs()
+        Coordinate::manhattan_distance(self, other)
+    }
+
+    /// The z component of the 3D cross product, positive when `other` lies counterclockwise of `self`
+    /// with y pointing up (clockwise on screen, where y points down).
+    pub fn cross(&self, other: &Point) -> i64 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Rotates 90 degrees around the origin, clockwise with y pointing down as in a grid,
+    /// so rotating the offset of a direction gives the offset after turning right.
+    pub fn rotate90(&self) -> Self {
+        Point::new(-self.y, self.x)
     }
 
     /// Returns an iterator over the surrounding points of this point.
@@ -32,6 +48,48 @@ impl Point {
     }
 }
 
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Coordinate for Point {
+    fn components(&self) -> impl Iterator<Item = i64> {
+        [self.x, self.y].into_iter()
+    }
+
+    fn from_components(mut components: impl Iterator<Item = i64>) -> Self {
+        Point::new(components.next().unwrap(), components.next().unwrap())
+    }
+}
+
 /// Custom iterator to iterate over the adjacent points of a Point.
 pub struct AdjacentPoints<'a> {
     center: &'a Point,
@@ -109,4 +167,35 @@ mod tests {
         // Assert
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_operators() {
+        // Arrange
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -3);
+
+        // Act & Assert
+        assert_eq!(a + b, Point::new(5, -1));
+        assert_eq!(a - b, Point::new(-3, 5));
+        assert_eq!(a * 3, Point::new(3, 6));
+        assert_eq!(-a, Point::new(-1, -2));
+    }
+
+    #[test]
+    fn test_cross() {
+        // Act & Assert
+        assert_eq!(Point::new(1, 0).cross(&Point::new(0, 1)), 1);
+        assert_eq!(Point::new(0, 1).cross(&Point::new(1, 0)), -1);
+        assert_eq!(Point::new(2, 4).cross(&Point::new(1, 2)), 0);
+    }
+
+    #[test]
+    fn test_rotate90_turns_right() {
+        // Act & Assert
+        for dir in crate::Direction4::ALL {
+            let (dx, dy) = dir.delta();
+            let (rx, ry) = dir.turn_right().delta();
+            assert_eq!(Point::new(dx, dy).rotate90(), Point::new(rx, ry));
+        }
+    }
 }
@@ -1,3 +1,7 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::Coordinate;
+
 #[derive(Debug, PartialEq, Copy, Clone, Eq, Hash, PartialOrd, Ord)]
 pub struct Point3d {
     pub x: i64,
@@ -16,6 +20,85 @@ impl Point3d {
         let dz = (other.z - self.z) as f64;
         (dx.powi(2) + dy.powi(2) + dz.powi(2)).sqrt()
     }
+
+    /// The cross product, perpendicular to both vectors following the right-hand rule.
+    pub fn cross(&self, other: &Point3d) -> Point3d {
+        Point3d::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// The 6 points that share a face with this point: -x, +x, -y, +y, -z, +z.
+    pub fn neighbors6(&self) -> impl Iterator<Item = Point3d> {
+        let center = *self;
+        [
+            (-1, 0, 0),
+            (1, 0, 0),
+            (0, -1, 0),
+            (0, 1, 0),
+            (0, 0, -1),
+            (0, 0, 1),
+        ]
+        .into_iter()
+        .map(move |(dx, dy, dz)| center + Point3d::new(dx, dy, dz))
+    }
+
+    /// The 26 points that share a face, edge or corner with this point, ordered by z, y and then x.
+    pub fn neighbors26(&self) -> impl Iterator<Item = Point3d> {
+        let center = *self;
+        (-1..=1)
+            .flat_map(|dz| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dx| (dx, dy, dz))))
+            .filter(|&offset| offset != (0, 0, 0))
+            .map(move |(dx, dy, dz)| center + Point3d::new(dx, dy, dz))
+    }
+}
+
+impl Add for Point3d {
+    type Output = Point3d;
+
+    fn add(self, other: Point3d) -> Point3d {
+        Point3d::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3d {
+    type Output = Point3d;
+
+    fn sub(self, other: Point3d) -> Point3d {
+        Point3d::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<i64> for Point3d {
+    type Output = Point3d;
+
+    fn mul(self, factor: i64) -> Point3d {
+        Point3d::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl Neg for Point3d {
+    type Output = Point3d;
+
+    fn neg(self) -> Point3d {
+        Point3d::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Coordinate for Point3d {
+    fn components(&self) -> impl Iterator<Item = i64> {
+        [self.x, self.y, self.z].into_iter()
+    }
+
+    fn from_components(mut components: impl Iterator<Item = i64>) -> Self {
+        Point3d::new(
+            components.next().unwrap(),
+            components.next().unwrap(),
+            components.next().unwrap(),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -35,4 +118,59 @@ mod tests {
         // Assert
         assert!((actual - expected).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn test_operators() {
+        // Arrange
+        let a = Point3d::new(1, 2, 3);
+        let b = Point3d::new(4, -3, 0);
+
+        // Act & Assert
+        assert_eq!(a + b, Point3d::new(5, -1, 3));
+        assert_eq!(a - b, Point3d::new(-3, 5, 3));
+        assert_eq!(a * 2, Point3d::new(2, 4, 6));
+        assert_eq!(-a, Point3d::new(-1, -2, -3));
+    }
+
+    #[test]
+    fn test_cross() {
+        // Arrange
+        let x = Point3d::new(1, 0, 0);
+        let y = Point3d::new(0, 1, 0);
+
+        // Act
+        let actual = x.cross(&y);
+
+        // Assert
+        assert_eq!(actual, Point3d::new(0, 0, 1));
+        assert_eq!(y.cross(&x), -actual);
+    }
+
+    #[test]
+    fn test_neighbors6() {
+        // Arrange
+        let point = Point3d::new(1, 1, 1);
+
+        // Act
+        let actual: Vec<Point3d> = point.neighbors6().collect();
+
+        // Assert
+        assert_eq!(actual.len(), 6);
+        assert!(actual.iter().all(|n| n.manhattan_distance(&point) == 1));
+    }
+
+    #[test]
+    fn test_neighbors26() {
+        // Arrange
+        let point = Point3d::new(0, 0, 0);
+
+        // Act
+        let actual: Vec<Point3d> = point.neighbors26().collect();
+
+        // Assert
+        assert_eq!(actual.len(), 26);
+        assert_eq!(actual[0], Point3d::new(-1, -1, -1));
+        assert_eq!(actual[25], Point3d::new(1, 1, 1));
+        assert!(actual.iter().all(|n| n.chebyshev_distance(&point) == 1));
+    }
 }
@@ -0,0 +1,540 @@
+//! Polygons with integer vertices.
+//!
+//! [`Polygon`] treats vertices as lattice points and measures the shape geometrically.
+//! [`RectilinearPolygon`] treats vertices and edges as grid tiles, where a tile is inside the
+//! polygon when it lies on its boundary or is enclosed by it.
+
+use crate::{Grid, Point, Rect};
+
+/// The direction in which the vertices of a polygon go around, with y pointing up as in maths.
+/// With y pointing down, as in a grid, the directions appear mirrored.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Orientation {
+    Counterclockwise,
+    Clockwise,
+    /// All vertices lie on a single line, so the polygon has no area.
+    Degenerate,
+}
+
+/// A closed polygon through lattice points, with arbitrary edges. All measures are computed with
+/// exact integer arithmetic; only [`Polygon::perimeter`] needs floating point for diagonal edges.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// Creates the polygon from its vertices in order, the last vertex connects back to the first.
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// The edges as pairs of consecutive vertices, including the edge back to the first vertex.
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    /// Twice the signed area by the shoelace formula, positive for counterclockwise vertices.
+    /// Doubling keeps the result integer.
+    pub fn doubled_signed_area(&self) -> i128 {
+        self.edges().map(|(a, b)| cross(wide(&a), wide(&b))).sum()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.doubled_signed_area().unsigned_abs() as f64 / 2.0
+    }
+
+    /// The euclidean length of all edges.
+    pub fn perimeter(&self) -> f64 {
+        self.edges().map(|(a, b)| a.distance_to(&b)).sum()
+    }
+
+    /// The number of lattice points on the edges, vertices included.
+    pub fn boundary_points(&self) -> u128 {
+        self.edges()
+            .map(|(a, b)| {
+                let (dx, dy) = offset(&a, &b);
+                gcd(dx.unsigned_abs(), dy.unsigned_abs())
+            })
+            .sum()
+    }
+
+    /// The number of lattice points strictly inside the polygon, using Pick's theorem:
+    /// `area = interior + boundary / 2 - 1`. Only valid for simple polygons; degenerate polygons
+    /// have no interior and give 0.
+    pub fn interior_points(&self) -> u128 {
+        if self.orientation() == Orientation::Degenerate {
+            return 0;
+        }
+        (self.doubled_signed_area().unsigned_abs() + 2 - self.boundary_points()) / 2
+    }
+
+    /// How often the polygon winds around `p` counterclockwise, negative when winding clockwise.
+    /// Points on the boundary have no well defined winding number, see [`Polygon::contains`].
+    pub fn winding_number(&self, p: &Point) -> i64 {
+        let mut winding = 0;
+        for (a, b) in self.edges() {
+            let side = cross(offset(&a, &b), offset(&a, p));
+            if a.y <= p.y && p.y < b.y && side > 0 {
+                winding += 1;
+            } else if b.y <= p.y && p.y < a.y && side < 0 {
+                winding -= 1;
+            }
+        }
+
+        winding
+    }
+
+    pub fn is_on_boundary(&self, p: &Point) -> bool {
+        self.edges().any(|(a, b)| {
+            cross(offset(&a, &b), offset(&a, p)) == 0
+                && p.x >= a.x.min(b.x)
+                && p.x <= a.x.max(b.x)
+                && p.y >= a.y.min(b.y)
+                && p.y <= a.y.max(b.y)
+        })
+    }
+
+    /// Checks if `p` lies on the boundary or has a non-zero winding number.
+    pub fn contains(&self, p: &Point) -> bool {
+        self.is_on_boundary(p) || self.winding_number(p) != 0
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        match self.doubled_signed_area().signum() {
+            1 => Orientation::Counterclockwise,
+            -1 => Orientation::Clockwise,
+            _ => Orientation::Degenerate,
+        }
+    }
+
+    /// Checks if every turn at a vertex goes the same way. Straight angles are allowed.
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        let turns = (0..n).map(|i| {
+            let (a, b, c) = (
+                self.vertices[i],
+                self.vertices[(i + 1) % n],
+                self.vertices[(i + 2) % n],
+            );
+            cross(offset(&a, &b), offset(&b, &c)).signum()
+        });
+
+        let mut direction = 0;
+        for turn in turns.filter(|&turn| turn != 0) {
+            if direction != 0 && turn != direction {
+                return false;
+            }
+            direction = turn;
+        }
+        true
+    }
+}
+
+/// The coordinates of `p` widened to i128, so products of coordinates can't overflow.
+fn wide(p: &Point) -> (i128, i128) {
+    (p.x as i128, p.y as i128)
+}
+
+/// The offset from `from` to `to`, computed in i128.
+fn offset(from: &Point, to: &Point) -> (i128, i128) {
+    (to.x as i128 - from.x as i128, to.y as i128 - from.y as i128)
+}
+
+fn cross(a: (i128, i128), b: (i128, i128)) -> i128 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// A closed polygon whose consecutive vertices share a row or column, so all edges are
+/// horizontal or vertical.
+///
+/// The plane is compressed to the distinct vertex columns and rows plus the gaps between them, which
+/// keeps the inside test independent of the size of the coordinates. Prefix sums over the compressed
+/// cells answer whether a rect is fully inside in constant time.
+#[derive(Debug, Clone)]
+pub struct RectilinearPolygon {
+    vertices: Vec<Point>,
+    columns: Vec<(i64, i64)>,
+    rows: Vec<(i64, i64)>,
+    /// `outside_counts[y][x]` is the number of outside cells above and left of compressed cell (x, y).
+    outside_counts: Vec<Vec<u32>>,
+}
+
+impl RectilinearPolygon {
+    /// Builds the polygon from its vertices in order, the last vertex connects back to the first.
+    /// Returns an error if two consecutive vertices are not in the same row or column.
+    pub fn new(vertices: &[Point]) -> Result<Self, String> {
+        if vertices.is_empty() {
+            return Err("a polygon needs at least one vertex".to_string());
+        }
+        let edges = || vertices.iter().zip(vertices.iter().cycle().skip(1));
+        if let Some((a, b)) = edges().find(|(a, b)| a.x != b.x && a.y != b.y) {
+            return Err(format!(
+                "edge from ({}, {}) to ({}, {}) is not horizontal or vertical",
+                a.x, a.y, b.x, b.y
+            ));
+        }
+
+        let columns = compress(vertices.iter().map(|v| v.x).collect());
+        let rows = compress(vertices.iter().map(|v| v.y).collect());
+        let to_cell = |p: &Point| {
+            Point::new(
+                index_of(&columns, p.x).unwrap() as i64,
+                index_of(&rows, p.y).unwrap() as i64,
+            )
+        };
+
+        let mut is_boundary: Grid<bool> = Grid::from_fn(columns.len(), rows.len(), |_| false);
+        for (a, b) in edges() {
+            let rect = Rect::new(&to_cell(a), &to_cell(b));
+            for cell in rect.points() {
+                is_boundary.set(&cell, true);
+            }
+        }
+
+        // The compressed plane has a padding row and column on every side, so flooding from a corner
+        // reaches every outside cell.
+        let mut is_outside: Grid<bool> = is_boundary.map(|_| false);
+        let mut stack = vec![Point::new(0, 0)];
+        is_outside.set(&Point::new(0, 0), true);
+        while let Some(cell) = stack.pop() {
+            for (neighbor, &boundary) in is_boundary.neighbors4(&cell) {
+                if !boundary && !is_outside.at_point(&neighbor).unwrap() {
+                    is_outside.set(&neighbor, true);
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        let mut outside_counts = vec![vec![0; columns.len() + 1]; rows.len() + 1];
+        for (cell, &outside) in is_outside.iter() {
+            let (x, y) = (cell.x as usize, cell.y as usize);
+            outside_counts[y + 1][x + 1] = outside_counts[y][x + 1] + outside_counts[y + 1][x]
+                - outside_counts[y][x]
+                + outside as u32;
+        }
+
+        Ok(RectilinearPolygon {
+            vertices: vertices.to_vec(),
+            columns,
+            rows,
+            outside_counts,
+        })
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        self.contains_rect(&Rect::new(p, p))
+    }
+
+    /// Checks if every tile of `rect` lies on the boundary of the polygon or inside it.
+    pub fn contains_rect(&self, rect: &Rect) -> bool {
+        let cells = (
+            index_of(&self.columns, rect.left),
+            index_of(&self.rows, rect.top),
+            index_of(&self.columns, rect.right),
+            index_of(&self.rows, rect.bottom),
+        );
+        let (Some(left), Some(top), Some(right), Some(bottom)) = cells else {
+            return false;
+        };
+
+        let counts = &self.outside_counts;
+        let outside = counts[bottom + 1][right + 1] + counts[top][left]
+            - counts[top][right + 1]
+            - counts[bottom + 1][left];
+        outside == 0
+    }
+
+    /// The largest rect with two opposite corners on vertices that lies fully inside the polygon.
+    /// When several rects have the same area, the first pair of vertices wins.
+    pub fn largest_inscribed_rect(&self) -> Option<Rect> {
+        let mut largest: Option<Rect> = None;
+        for (i, a) in self.vertices.iter().enumerate() {
+            for b in &self.vertices[i + 1..] {
+                let rect = Rect::new(a, b);
+                let is_larger = largest.is_none_or(|best| rect.area() > best.area());
+                if is_larger && self.contains_rect(&rect) {
+                    largest = Some(rect);
+                }
+            }
+        }
+
+        largest
+    }
+}
+
+/// Splits the axis into inclusive ranges: one per distinct coordinate, one per gap between
+/// neighbouring coordinates, and a padding range before the first and after the last coordinate.
+fn compress(mut coordinates: Vec<i64>) -> Vec<(i64, i64)> {
+    coordinates.sort_unstable();
+    coordinates.dedup();
+
+    let first = coordinates[0];
+    let mut ranges = vec![(first - 1, first - 1)];
+    for (i, &c) in coordinates.iter().enumerate() {
+        ranges.push((c, c));
+        if let Some(&next) = coordinates.get(i + 1) {
+            if next - c > 1 {
+                ranges.push((c + 1, next - 1));
+            }
+        }
+    }
+    let last = coordinates[coordinates.len() - 1];
+    ranges.push((last + 1, last + 1));
+    ranges
+}
+
+/// The range containing `value`, or None if it lies beyond the padding.
+fn index_of(ranges: &[(i64, i64)], value: i64) -> Option<usize> {
+    let index = ranges.partition_point(|&(_, end)| end < value);
+    ranges
+        .get(index)
+        .filter(|&&(start, _)| start <= value)
+        .map(|_| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// The red tiles of the day 9 sample.
+    fn sample() -> RectilinearPolygon {
+        let vertices = [
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ]
+        .map(|(x, y)| Point::new(x, y));
+        RectilinearPolygon::new(&vertices).unwrap()
+    }
+
+    #[rstest]
+    #[case(Point::new(7, 1), true)]
+    #[case(Point::new(10, 6), true)]
+    #[case(Point::new(2, 4), true)]
+    #[case(Point::new(8, 4), true)]
+    #[case(Point::new(6, 2), false)]
+    #[case(Point::new(8, 6), false)]
+    #[case(Point::new(12, 1), false)]
+    #[case(Point::new(-100, 100), false)]
+    fn test_contains(#[case] point: Point, #[case] expected: bool) {
+        // Act
+        let actual = sample().contains(&point);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(Rect::new(&Point::new(9, 5), &Point::new(2, 3)), true)]
+    #[case(Rect::new(&Point::new(7, 1), &Point::new(11, 7)), false)]
+    #[case(Rect::new(&Point::new(2, 3), &Point::new(11, 3)), true)]
+    #[case(Rect::new(&Point::new(7, 3), &Point::new(11, 5)), true)]
+    fn test_contains_rect(#[case] rect: Rect, #[case] expected: bool) {
+        // Act
+        let actual = sample().contains_rect(&rect);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_largest_inscribed_rect() {
+        // Act
+        let actual = sample().largest_inscribed_rect();
+
+        // Assert
+        assert_eq!(
+            actual,
+            Some(Rect::new(&Point::new(9, 5), &Point::new(2, 3)))
+        );
+        assert_eq!(actual.unwrap().area(), 24);
+    }
+
+    #[test]
+    fn test_narrow_notch_is_inside() {
+        // Arrange: a U shape whose notch between x = 1 and x = 2 has no tiles of its own.
+        let vertices = [
+            (0, 0),
+            (1, 0),
+            (1, 2),
+            (2, 2),
+            (2, 0),
+            (3, 0),
+            (3, 3),
+            (0, 3),
+        ]
+        .map(|(x, y)| Point::new(x, y));
+        let polygon = RectilinearPolygon::new(&vertices).unwrap();
+
+        // Act
+        let actual = polygon.largest_inscribed_rect();
+
+        // Assert
+        assert_eq!(
+            actual,
+            Some(Rect::new(&Point::new(0, 0), &Point::new(3, 3)))
+        );
+    }
+
+    fn polygon(vertices: &[(i64, i64)]) -> Polygon {
+        Polygon::new(vertices.iter().map(|&(x, y)| Point::new(x, y)).collect())
+    }
+
+    #[rstest]
+    #[case(polygon(&[(0, 0), (4, 0), (4, 3)]), 12, 8, 3)]
+    #[case(polygon(&[(0, 0), (0, 3), (3, 3), (3, 0)]), -18, 12, 4)]
+    #[case(polygon(&[(0, 0), (2, 0), (2, 2), (1, 1), (0, 2)]), 6, 8, 0)]
+    #[case(polygon(&[(0, 0), (1, 1), (2, 2)]), 0, 4, 0)]
+    fn test_area_and_lattice_points(
+        #[case] polygon: Polygon,
+        #[case] doubled_signed_area: i128,
+        #[case] boundary: u128,
+        #[case] interior: u128,
+    ) {
+        // Act & Assert
+        assert_eq!(polygon.doubled_signed_area(), doubled_signed_area);
+        assert_eq!(polygon.area(), doubled_signed_area.abs() as f64 / 2.0);
+        assert_eq!(polygon.boundary_points(), boundary);
+        assert_eq!(polygon.interior_points(), interior);
+    }
+
+    #[test]
+    fn test_large_coordinates_are_exact() {
+        // Arrange
+        let (low, high) = (1 << 40, 1 << 41);
+        let square = polygon(&[(low, low), (high, low), (high, high), (low, high)]);
+
+        // Act & Assert
+        assert_eq!(square.doubled_signed_area(), 1 << 81);
+        assert_eq!(square.orientation(), Orientation::Counterclockwise);
+        assert_eq!(square.interior_points(), (1 << 80) - (1 << 41) + 1);
+        assert!(square.is_convex());
+        assert_eq!(square.winding_number(&Point::new(low + 1, high - 1)), 1);
+        assert_eq!(square.boundary_points(), 1 << 42);
+        assert!(square.is_on_boundary(&Point::new(high, low + 12345)));
+        assert!(!square.contains(&Point::new(high + 1, low)));
+    }
+
+    #[test]
+    fn test_edges_spanning_most_of_the_i64_range() {
+        // Arrange
+        // The horizontal edges are 2^63 long, one more than i64::MAX.
+        let (left, right) = (-(1 << 62), 1 << 62);
+        let rect = polygon(&[(left, 0), (right, 0), (right, 2), (left, 2)]);
+
+        // Act & Assert
+        assert_eq!(rect.doubled_signed_area(), 1 << 65);
+        assert_eq!(rect.boundary_points(), (1 << 64) + 4);
+        assert_eq!(rect.interior_points(), (1 << 63) - 1);
+    }
+
+    #[test]
+    fn test_perimeter() {
+        // Arrange
+        let triangle = polygon(&[(0, 0), (4, 0), (4, 3)]);
+
+        // Act
+        let actual = triangle.perimeter();
+
+        // Assert
+        assert!((actual - 12.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    #[case(Point::new(1, 1), 1, true)]
+    #[case(Point::new(2, 2), 1, true)]
+    #[case(Point::new(4, 1), 0, false)]
+    #[case(Point::new(-1, 2), 0, false)]
+    fn test_winding_number_and_contains(
+        #[case] point: Point,
+        #[case] winding: i64,
+        #[case] contains: bool,
+    ) {
+        // Arrange
+        let square = polygon(&[(0, 0), (3, 0), (3, 3), (0, 3)]);
+
+        // Act & Assert
+        assert_eq!(square.winding_number(&point), winding);
+        assert_eq!(square.contains(&point), contains);
+    }
+
+    #[rstest]
+    #[case(Point::new(2, 0))]
+    #[case(Point::new(3, 3))]
+    #[case(Point::new(0, 1))]
+    fn test_contains_boundary(#[case] point: Point) {
+        // Arrange
+        let square = polygon(&[(0, 0), (3, 0), (3, 3), (0, 3)]);
+
+        // Act & Assert
+        assert!(square.is_on_boundary(&point));
+        assert!(square.contains(&point));
+    }
+
+    #[test]
+    fn test_winding_number_of_clockwise_polygon() {
+        // Arrange
+        let square = polygon(&[(0, 0), (0, 3), (3, 3), (3, 0)]);
+
+        // Act
+        let actual = square.winding_number(&Point::new(1, 2));
+
+        // Assert
+        assert_eq!(actual, -1);
+    }
+
+    #[rstest]
+    #[case(polygon(&[(0, 0), (3, 0), (3, 3)]), Orientation::Counterclockwise, true)]
+    #[case(polygon(&[(0, 0), (3, 3), (3, 0)]), Orientation::Clockwise, true)]
+    #[case(polygon(&[(0, 0), (1, 1), (2, 2)]), Orientation::Degenerate, true)]
+    #[case(polygon(&[(0, 0), (2, 0), (2, 2), (1, 1), (0, 2)]), Orientation::Counterclockwise, false)]
+    #[case(polygon(&[(0, 0), (1, 0), (2, 0), (2, 2), (0, 2)]), Orientation::Counterclockwise, true)]
+    fn test_orientation_and_convexity(
+        #[case] polygon: Polygon,
+        #[case] orientation: Orientation,
+        #[case] is_convex: bool,
+    ) {
+        // Act & Assert
+        assert_eq!(polygon.orientation(), orientation);
+        assert_eq!(polygon.is_convex(), is_convex);
+    }
+
+    #[test]
+    fn test_new_rejects_diagonal_edge() {
+        // Act
+        let actual = RectilinearPolygon::new(&[Point::new(0, 0), Point::new(1, 1)]);
+
+        // Assert
+        assert_eq!(
+            actual.unwrap_err(),
+            "edge from (0, 0) to (1, 1) is not horizontal or vertical"
+        );
+    }
+}
@@ -1,4 +1,6 @@
 use std::fmt;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
 
 #[derive(Clone, Copy, PartialEq)]
 pub struct Range<T>
@@ -26,6 +28,11 @@ where
         Range { start, length }
     }
 
+    /// Creates the half-open range `start..end`.
+    pub fn from_bounds(start: T, end: T) -> Self {
+        Range::new(start, end - start)
+    }
+
     pub fn start(&self) -> T {
         self.start
     }
@@ -54,6 +61,41 @@ where
         self.start < other.end() && other.start < self.end()
     }
 
+    /// The values in both ranges, or None if they don't overlap.
+    pub fn intersection(&self, other: &Range<T>) -> Option<Range<T>> {
+        self.overlaps(other).then(|| {
+            let start = if self.start > other.start {
+                self.start
+            } else {
+                other.start
+            };
+            let end = if self.end() < other.end() {
+                self.end()
+            } else {
+                other.end()
+            };
+            Range::from_bounds(start, end)
+        })
+    }
+
+    /// The range covering both ranges if they overlap or touch, so that the result contains
+    /// exactly the values of both. Returns None if there is a gap between them.
+    pub fn union_if_touching(&self, other: &Range<T>) -> Option<Range<T>> {
+        (self.start <= other.end() && other.start <= self.end()).then(|| {
+            let start = if self.start < other.start {
+                self.start
+            } else {
+                other.start
+            };
+            let end = if self.end() > other.end() {
+                self.end()
+            } else {
+                other.end()
+            };
+            Range::from_bounds(start, end)
+        })
+    }
+
     pub fn shifted(&self, delta: T) -> Self {
         Range {
             start: self.start + delta,
@@ -153,6 +195,113 @@ where
     }
 }
 
+impl<T> Range<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + Sub<Output = T> + Default + From<u8>,
+{
+    /// Creates the range `first..=last`.
+    pub fn from_inclusive(first: T, last: T) -> Self {
+        Range::new(first, last - first + T::from(1))
+    }
+
+    /// The last value in the range, only meaningful if the range is not empty.
+    pub fn last(&self) -> T {
+        self.end() - T::from(1)
+    }
+}
+
+impl<T> Range<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + Sub<Output = T> + Default,
+    std::ops::Range<T>: Iterator<Item = T>,
+{
+    /// Iterates over the values in ascending order.
+    pub fn iter(&self) -> std::ops::Range<T> {
+        self.start..self.end()
+    }
+}
+
+impl<T> IntoIterator for Range<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + Sub<Output = T> + Default,
+    std::ops::Range<T>: Iterator<Item = T>,
+{
+    type Item = T;
+    type IntoIter = std::ops::Range<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> From<std::ops::Range<T>> for Range<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + Sub<Output = T> + Default,
+{
+    fn from(range: std::ops::Range<T>) -> Self {
+        Range::from_bounds(range.start, range.end)
+    }
+}
+
+impl<T> From<std::ops::RangeInclusive<T>> for Range<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + Sub<Output = T> + Default + From<u8>,
+{
+    fn from(range: std::ops::RangeInclusive<T>) -> Self {
+        Range::from_inclusive(*range.start(), *range.end())
+    }
+}
+
+impl<T> From<Range<T>> for std::ops::Range<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + Sub<Output = T> + Default,
+{
+    fn from(range: Range<T>) -> Self {
+        range.start()..range.end()
+    }
+}
+
+impl<T> From<Range<T>> for std::ops::RangeInclusive<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + Sub<Output = T> + Default + From<u8>,
+{
+    fn from(range: Range<T>) -> Self {
+        range.start()..=range.last()
+    }
+}
+
+impl<T> FromStr for Range<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + Sub<Output = T> + Default + From<u8> + FromStr,
+{
+    type Err = String;
+
+    /// Parses `a..b` as a half-open range, and both `a..=b` and `a-b` as inclusive ranges.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |number: &str| {
+            number
+                .trim()
+                .parse::<T>()
+                .map_err(|_| format!("invalid number '{number}' in range '{s}'"))
+        };
+
+        if let Some((first, last)) = s.split_once("..=") {
+            Ok(Range::from_inclusive(parse(first)?, parse(last)?))
+        } else if let Some((start, end)) = s.split_once("..") {
+            Ok(Range::from_bounds(parse(start)?, parse(end)?))
+        } else if let Some(dash) = s.trim_start().get(1..).and_then(|rest| rest.find('-')) {
+            // The first character is skipped so a negative start is not mistaken for the separator.
+            let s = s.trim_start();
+            Ok(Range::from_inclusive(
+                parse(&s[..dash + 1])?,
+                parse(&s[dash + 2..])?,
+            ))
+        } else {
+            Err(format!("invalid range '{s}'"))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -264,4 +413,89 @@ mod tests {
         // Assert
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_constructors() {
+        // Act & Assert
+        assert_eq!(Range::from_inclusive(3, 5), Range::new(3, 3));
+        assert_eq!(Range::from_bounds(3, 5), Range::new(3, 2));
+        assert_eq!(Range::from_inclusive(3, 5).last(), 5);
+    }
+
+    #[rstest]
+    #[case("3-5", Range::new(3, 3))]
+    #[case("3..5", Range::new(3, 2))]
+    #[case("3..=5", Range::new(3, 3))]
+    #[case("-5--3", Range::new(-5, 3))]
+    #[case("-5..0", Range::new(-5, 5))]
+    #[case(" 10 - 14 ", Range::new(10, 5))]
+    fn test_from_str(#[case] input: &str, #[case] expected: Range<i64>) {
+        // Act
+        let actual: Range<i64> = input.parse().unwrap();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case("3")]
+    #[case("a-b")]
+    #[case("3..")]
+    fn test_from_str_invalid(#[case] input: &str) {
+        // Act
+        let actual = input.parse::<Range<i64>>();
+
+        // Assert
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_std_conversions() {
+        // Arrange
+        let range = Range::new(3, 3);
+
+        // Act & Assert
+        assert_eq!(std::ops::Range::from(range), 3..6);
+        assert_eq!(std::ops::RangeInclusive::from(range), 3..=5);
+        assert_eq!(Range::from(3..6), range);
+        assert_eq!(Range::from(3..=5), range);
+    }
+
+    #[test]
+    fn test_iterate() {
+        // Act
+        let actual: Vec<u32> = Range::from_inclusive(3u32, 6).into_iter().collect();
+
+        // Assert
+        assert_eq!(actual, vec![3, 4, 5, 6]);
+        assert_eq!(Range::new(3, 0).iter().count(), 0);
+    }
+
+    #[rstest]
+    #[case(
+        Range::new(0, 10),
+        Range::new(5, 10),
+        Some(Range::new(5, 5)),
+        Some(Range::new(0, 15))
+    )]
+    #[case(Range::new(0, 10), Range::new(10, 5), None, Some(Range::new(0, 15)))]
+    #[case(Range::new(0, 10), Range::new(11, 5), None, None)]
+    #[case(
+        Range::new(0, 10),
+        Range::new(2, 3),
+        Some(Range::new(2, 3)),
+        Some(Range::new(0, 10))
+    )]
+    fn test_intersection_and_union(
+        #[case] a: Range<i64>,
+        #[case] b: Range<i64>,
+        #[case] intersection: Option<Range<i64>>,
+        #[case] union: Option<Range<i64>>,
+    ) {
+        // Act & Assert
+        assert_eq!(a.intersection(&b), intersection);
+        assert_eq!(b.intersection(&a), intersection);
+        assert_eq!(a.union_if_touching(&b), union);
+        assert_eq!(b.union_if_touching(&a), union);
+    }
 }
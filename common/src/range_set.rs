@@ -0,0 +1,304 @@
+use std::fmt;
+use std::ops::{Add, Sub};
+
+use crate::Range;
+
+/// A set of values stored as sorted, disjoint ranges. Overlapping or touching ranges are merged on
+/// insert, so every value is covered by at most one range and no two ranges could be joined.
+#[derive(Clone, PartialEq)]
+pub struct RangeSet<T>
+where
+    T: PartialOrd + Copy,
+{
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> fmt::Debug for RangeSet<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.ranges.iter()).finish()
+    }
+}
+
+impl<T> Default for RangeSet<T>
+where
+    T: PartialOrd + Copy,
+{
+    fn default() -> Self {
+        RangeSet { ranges: vec![] }
+    }
+}
+
+impl<T> RangeSet<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + Sub<Output = T> + Default,
+{
+    pub fn new() -> Self {
+        RangeSet { ranges: vec![] }
+    }
+
+    /// The disjoint ranges in ascending order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Range<T>> {
+        self.ranges.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Checks if `n` lies in one of the ranges, using a binary search.
+    pub fn contains(&self, n: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end() <= n);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.contains(n))
+    }
+
+    /// The number of values covered by the set.
+    pub fn total_length(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::default(), |total, range| total + range.length())
+    }
+
+    /// Adds all values of `range`, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.length() <= T::default() {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|r| r.end() < range.start());
+        let last = self.ranges.partition_point(|r| r.start() <= range.end());
+        let mut start = range.start();
+        let mut end = range.end();
+        if first < last {
+            start = min(start, self.ranges[first].start());
+            end = max(end, self.ranges[last - 1].end());
+        }
+        self.ranges
+            .splice(first..last, [Range::new(start, end - start)]);
+    }
+
+    /// Removes all values of `range`, shrinking or splitting the ranges it overlaps.
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.length() <= T::default() {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|r| r.end() <= range.start());
+        let last = self.ranges.partition_point(|r| r.start() < range.end());
+        if first >= last {
+            return;
+        }
+
+        let mut remaining = vec![];
+        let first_range = self.ranges[first];
+        if first_range.start() < range.start() {
+            remaining.push(Range::new(
+                first_range.start(),
+                range.start() - first_range.start(),
+            ));
+        }
+        let last_range = self.ranges[last - 1];
+        if last_range.end() > range.end() {
+            remaining.push(Range::new(range.end(), last_range.end() - range.end()));
+        }
+        self.ranges.splice(first..last, remaining);
+    }
+
+    /// The values in either set.
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut result = self.clone();
+        for range in &other.ranges {
+            result.insert(*range);
+        }
+        result
+    }
+
+    /// The values in both sets.
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+            ranges.extend(a.intersection(&b));
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// The values in this set that are not in `other`.
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut result = self.clone();
+        for range in &other.ranges {
+            result.remove(*range);
+        }
+        result
+    }
+
+    /// The values within `bounds` that are not in this set.
+    pub fn complement(&self, bounds: Range<T>) -> RangeSet<T> {
+        RangeSet::from_iter([bounds]).difference(self)
+    }
+}
+
+impl<T> FromIterator<Range<T>> for RangeSet<T>
+where
+    T: PartialOrd + Copy + Add<Output = T> + Sub<Output = T> + Default,
+{
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+impl<T> IntoIterator for RangeSet<T>
+where
+    T: PartialOrd + Copy,
+{
+    type Item = Range<T>;
+    type IntoIter = std::vec::IntoIter<Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Builds a set from `(start, end)` pairs with an exclusive end.
+    fn set(ranges: &[(i64, i64)]) -> RangeSet<i64> {
+        ranges
+            .iter()
+            .map(|&(start, end)| Range::new(start, end - start))
+            .collect()
+    }
+
+    #[rstest]
+    #[case(&[(3, 6), (10, 15), (16, 21), (12, 19)], &[(3, 6), (10, 21)])]
+    #[case(&[(3, 6), (6, 8)], &[(3, 8)])]
+    #[case(&[(3, 6), (4, 5), (4, 5)], &[(3, 6)])]
+    #[case(&[(10, 12), (0, 2), (5, 5)], &[(0, 2), (10, 12)])]
+    #[case(&[(0, 2), (4, 6), (8, 10), (1, 9)], &[(0, 10)])]
+    fn test_insert_merges(#[case] inserted: &[(i64, i64)], #[case] expected: &[(i64, i64)]) {
+        // Act
+        let actual = set(inserted);
+
+        // Assert
+        let expected: Vec<Range<i64>> = expected
+            .iter()
+            .map(|&(start, end)| Range::new(start, end - start))
+            .collect();
+        assert_eq!(actual.ranges(), expected.as_slice());
+    }
+
+    #[rstest]
+    #[case((4, 6), &[(0, 4), (6, 10), (20, 30)])]
+    #[case((0, 10), &[(20, 30)])]
+    #[case((5, 25), &[(0, 5), (25, 30)])]
+    #[case((10, 20), &[(0, 10), (20, 30)])]
+    fn test_remove(#[case] removed: (i64, i64), #[case] expected: &[(i64, i64)]) {
+        // Arrange
+        let mut actual = set(&[(0, 10), (20, 30)]);
+
+        // Act
+        actual.remove(Range::new(removed.0, removed.1 - removed.0));
+
+        // Assert
+        assert_eq!(actual, set(expected));
+    }
+
+    #[rstest]
+    #[case(0, true)]
+    #[case(9, true)]
+    #[case(10, false)]
+    #[case(19, false)]
+    #[case(20, true)]
+    #[case(-1, false)]
+    #[case(30, false)]
+    fn test_contains(#[case] n: i64, #[case] expected: bool) {
+        // Arrange
+        let ranges = set(&[(0, 10), (20, 30)]);
+
+        // Act
+        let actual = ranges.contains(n);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        // Arrange
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (40, 50)]);
+
+        // Act & Assert
+        assert_eq!(a.union(&b), set(&[(0, 30), (40, 50)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 30)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20), (40, 50)]));
+    }
+
+    #[test]
+    fn test_complement() {
+        // Arrange
+        let ranges = set(&[(0, 10), (20, 30)]);
+
+        // Act
+        let actual = ranges.complement(Range::new(-5, 45));
+
+        // Assert
+        assert_eq!(actual, set(&[(-5, 0), (10, 20), (30, 40)]));
+    }
+
+    #[test]
+    fn test_total_length_and_iteration() {
+        // Arrange
+        let ranges = set(&[(3, 6), (10, 15), (16, 21), (12, 19)]);
+
+        // Act & Assert
+        assert_eq!(ranges.total_length(), 14);
+        assert_eq!(ranges.iter().count(), 2);
+        assert_eq!(
+            ranges
+                .into_iter()
+                .map(|range| range.start())
+                .collect::<Vec<_>>(),
+            vec![3, 10]
+        );
+    }
+}
@@ -29,6 +29,128 @@ impl Rect {
     pub fn is_corner(&self, p: &Point) -> bool {
         (p.x == self.left || p.x == self.right) && (p.y == self.top || p.y == self.bottom)
     }
+
+    /// The number of columns, counting both the left and right column.
+    pub fn width(&self) -> u64 {
+        (self.right - self.left) as u64 + 1
+    }
+
+    /// The number of rows, counting both the top and bottom row.
+    pub fn height(&self) -> u64 {
+        (self.bottom - self.top) as u64 + 1
+    }
+
+    /// The number of points inside the rect, including its border.
+    pub fn area(&self) -> u64 {
+        self.width() * self.height()
+    }
+
+    /// The rect covered by both rects, or None if they don't overlap.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let rect = Rect {
+            top: self.top.max(other.top),
+            left: self.left.max(other.left),
+            bottom: self.bottom.min(other.bottom),
+            right: self.right.min(other.right),
+        };
+        (rect.top <= rect.bottom && rect.left <= rect.right).then_some(rect)
+    }
+
+    pub fn overlaps(&self, other: &Rect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The parts of this rect that are not covered by `other`, as at most 4 disjoint rects:
+    /// full-width bands above and below `other`, and the parts left and right of it in between.
+    pub fn subtract(&self, other: &Rect) -> Vec<Rect> {
+        let Some(overlap) = self.intersection(other) else {
+            return vec![*self];
+        };
+
+        let mut parts = vec![];
+        if self.top < overlap.top {
+            parts.push(Rect {
+                bottom: overlap.top - 1,
+                ..*self
+            });
+        }
+        if overlap.bottom < self.bottom {
+            parts.push(Rect {
+                top: overlap.bottom + 1,
+                ..*self
+            });
+        }
+        if self.left < overlap.left {
+            parts.push(Rect {
+                top: overlap.top,
+                left: self.left,
+                bottom: overlap.bottom,
+                right: overlap.left - 1,
+            });
+        }
+        if overlap.right < self.right {
+            parts.push(Rect {
+                top: overlap.top,
+                left: overlap.right + 1,
+                bottom: overlap.bottom,
+                right: self.right,
+            });
+        }
+        parts
+    }
+
+    /// Grows the rect by `n` on every side. A negative `n` shrinks it, or returns None if that
+    /// would leave nothing of the rect.
+    pub fn expand(&self, n: i64) -> Option<Rect> {
+        let rect = Rect {
+            top: self.top - n,
+            left: self.left - n,
+            bottom: self.bottom + n,
+            right: self.right + n,
+        };
+        (rect.top <= rect.bottom && rect.left <= rect.right).then_some(rect)
+    }
+
+    /// Iterates over all points inside the rect, including its border, in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (left, right) = (self.left, self.right);
+        (self.top..=self.bottom).flat_map(move |y| (left..=right).map(move |x| Point::new(x, y)))
+    }
+
+    /// The number of points covered by at least one of `rects`. Sweeps over the distinct x
+    /// boundaries and, for every slab of columns between them, merges the covering row ranges.
+    pub fn union_area(rects: &[Rect]) -> u64 {
+        let mut xs: Vec<i64> = rects
+            .iter()
+            .flat_map(|rect| [rect.left, rect.right + 1])
+            .collect();
+        xs.sort_unstable();
+        xs.dedup();
+
+        let mut area = 0;
+        for slab in xs.windows(2) {
+            let (slab_left, slab_right) = (slab[0], slab[1]);
+            let mut rows: Vec<(i64, i64)> = rects
+                .iter()
+                .filter(|rect| rect.left <= slab_left && slab_right - 1 <= rect.right)
+                .map(|rect| (rect.top, rect.bottom + 1))
+                .collect();
+            rows.sort_unstable();
+
+            let mut covered_rows = 0;
+            let mut covered_until = i64::MIN;
+            for (start, end) in rows {
+                let start = start.max(covered_until);
+                if start < end {
+                    covered_rows += (end - start) as u64;
+                    covered_until = end;
+                }
+            }
+            area += covered_rows * (slab_right - slab_left) as u64;
+        }
+
+        area
+    }
 }
 
 #[cfg(test)]
@@ -100,4 +222,105 @@ mod tests {
         // Assert
         assert_eq!(actual, expected);
     }
+
+    fn rect(left: i64, top: i64, right: i64, bottom: i64) -> Rect {
+        Rect {
+            top,
+            left,
+            bottom,
+            right,
+        }
+    }
+
+    #[test]
+    fn test_dimensions() {
+        // Arrange
+        let rect = rect(2, 5, 11, 7);
+
+        // Act & Assert
+        assert_eq!(rect.width(), 10);
+        assert_eq!(rect.height(), 3);
+        assert_eq!(rect.area(), 30);
+    }
+
+    #[rstest]
+    #[case(rect(0, 0, 4, 4), rect(2, 3, 6, 8), Some(rect(2, 3, 4, 4)))]
+    #[case(rect(0, 0, 4, 4), rect(4, 4, 6, 6), Some(rect(4, 4, 4, 4)))]
+    #[case(rect(0, 0, 4, 4), rect(5, 0, 6, 4), None)]
+    #[case(rect(0, 0, 4, 4), rect(1, 1, 2, 2), Some(rect(1, 1, 2, 2)))]
+    fn test_intersection(#[case] a: Rect, #[case] b: Rect, #[case] expected: Option<Rect>) {
+        // Act
+        let actual = a.intersection(&b);
+
+        // Assert
+        assert_eq!(actual, expected);
+        assert_eq!(b.intersection(&a), expected);
+        assert_eq!(a.overlaps(&b), expected.is_some());
+    }
+
+    #[rstest]
+    #[case(rect(0, 0, 4, 4), rect(1, 1, 2, 2), 4)]
+    #[case(rect(0, 0, 4, 4), rect(-1, -1, 5, 5), 0)]
+    #[case(rect(0, 0, 4, 4), rect(6, 6, 8, 8), 1)]
+    #[case(rect(0, 0, 4, 4), rect(2, -3, 8, 8), 1)]
+    fn test_subtract(#[case] a: Rect, #[case] b: Rect, #[case] expected_parts: usize) {
+        // Act
+        let actual = a.subtract(&b);
+
+        // Assert
+        assert_eq!(actual.len(), expected_parts);
+        let covered = b.intersection(&a).map_or(0, |overlap| overlap.area());
+        assert_eq!(
+            actual.iter().map(Rect::area).sum::<u64>(),
+            a.area() - covered
+        );
+        for (i, part) in actual.iter().enumerate() {
+            assert!(!part.overlaps(&b));
+            assert!(actual[i + 1..].iter().all(|other| !part.overlaps(other)));
+        }
+    }
+
+    #[rstest]
+    #[case(rect(1, 1, 2, 3), 1, Some(rect(0, 0, 3, 4)))]
+    #[case(rect(1, 1, 2, 3), 0, Some(rect(1, 1, 2, 3)))]
+    #[case(rect(0, 0, 4, 4), -2, Some(rect(2, 2, 2, 2)))]
+    #[case(rect(0, 0, 4, 4), -3, None)]
+    #[case(rect(0, 0, 9, 2), -1, Some(rect(1, 1, 8, 1)))]
+    #[case(rect(0, 0, 9, 2), -2, None)]
+    fn test_expand(#[case] sut: Rect, #[case] n: i64, #[case] expected: Option<Rect>) {
+        // Act
+        let actual = sut.expand(n);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_points() {
+        // Act
+        let actual: Vec<Point> = rect(1, 0, 2, 1).points().collect();
+
+        // Assert
+        let expected = vec![
+            Point::new(1, 0),
+            Point::new(2, 0),
+            Point::new(1, 1),
+            Point::new(2, 1),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(vec![], 0)]
+    #[case(vec![rect(0, 0, 2, 2)], 9)]
+    #[case(vec![rect(0, 0, 2, 2), rect(1, 1, 3, 3)], 14)]
+    #[case(vec![rect(0, 0, 2, 2), rect(0, 0, 2, 2)], 9)]
+    #[case(vec![rect(0, 0, 0, 9), rect(0, 0, 9, 0), rect(5, 5, 6, 6)], 23)]
+    fn test_union_area(#[case] rects: Vec<Rect>, #[case] expected: u64) {
+        // Act
+        let actual = Rect::union_area(&rects);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
 }
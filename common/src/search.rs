@@ -0,0 +1,492 @@
+//! State-space search over a user-supplied `successors` function.
+//!
+//! States can be anything hashable: grid positions (`Point`), or richer states like a node index
+//! combined with extra flags. The `successors` function returns the states reachable in one step,
+//! for the weighted searches together with the cost of that step.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// The outcome of exploring everything reachable from a start state.
+#[derive(Debug, Clone)]
+pub struct SearchResult<S> {
+    start: S,
+    distances: HashMap<S, u64>,
+    predecessors: HashMap<S, S>,
+}
+
+impl<S> SearchResult<S>
+where
+    S: Eq + Hash + Clone,
+{
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+
+    /// The distance from the start to `state`, or None if `state` is unreachable.
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).copied()
+    }
+
+    /// The distances of all reachable states, including the start itself with distance 0.
+    pub fn distances(&self) -> &HashMap<S, u64> {
+        &self.distances
+    }
+
+    pub fn is_reachable(&self, state: &S) -> bool {
+        self.distances.contains_key(state)
+    }
+
+    /// The states on a shortest path from the start to `goal`, both included.
+    pub fn path_to(&self, goal: &S) -> Option<Vec<S>> {
+        if !self.is_reachable(goal) {
+            return None;
+        }
+
+        Some(reconstruct_path(&self.predecessors, goal.clone()))
+    }
+}
+
+/// Breadth-first search where every step costs 1.
+pub fn bfs<S, F, I>(start: S, mut successors: F) -> SearchResult<S>
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances: HashMap<S, u64> = HashMap::new();
+    let mut predecessors: HashMap<S, S> = HashMap::new();
+    let mut queue: VecDeque<S> = VecDeque::new();
+
+    distances.insert(start.clone(), 0);
+    queue.push_back(start.clone());
+
+    while let Some(state) = queue.pop_front() {
+        let distance = distances[&state];
+        for next in successors(&state) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    SearchResult {
+        start,
+        distances,
+        predecessors,
+    }
+}
+
+/// Breadth-first search that stops at the first state satisfying `is_goal`.
+/// Returns the shortest path from `start` to that state, both included.
+pub fn bfs_to<S, F, I, G>(start: S, mut successors: F, mut is_goal: G) -> Option<Vec<S>>
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut predecessors: HashMap<S, S> = HashMap::new();
+    let mut visited: HashSet<S> = HashSet::new();
+    let mut queue: VecDeque<S> = VecDeque::new();
+
+    visited.insert(start.clone());
+    queue.push_back(start);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            return Some(reconstruct_path(&predecessors, state));
+        }
+
+        for next in successors(&state) {
+            if visited.insert(next.clone()) {
+                predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Depth-first search, returning all reachable states in the order they were first visited.
+pub fn dfs<S, F, I>(start: S, mut successors: F) -> Vec<S>
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited: HashSet<S> = HashSet::new();
+    let mut order: Vec<S> = vec![];
+    let mut stack: Vec<S> = vec![start];
+
+    while let Some(state) = stack.pop() {
+        if !visited.insert(state.clone()) {
+            continue;
+        }
+
+        let nexts: Vec<S> = successors(&state).into_iter().collect();
+        // Push in reverse, so the first successor is visited first.
+        for next in nexts.into_iter().rev() {
+            if !visited.contains(&next) {
+                stack.push(next);
+            }
+        }
+        order.push(state);
+    }
+
+    order
+}
+
+/// Dijkstra's algorithm over non-negative step costs, exploring everything reachable from `start`.
+pub fn dijkstra<S, F, I>(start: S, successors: F) -> SearchResult<S>
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let (distances, predecessors, _) = best_first(start.clone(), successors, |_| 0, |_| false);
+    SearchResult {
+        start,
+        distances,
+        predecessors,
+    }
+}
+
+/// Dijkstra's algorithm that stops at the first state satisfying `is_goal`.
+/// Returns the cheapest path to that state and its total cost.
+pub fn dijkstra_to<S, F, I, G>(start: S, successors: F, is_goal: G) -> Option<(Vec<S>, u64)>
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// A* search. The `heuristic` must never overestimate the remaining cost to a goal, otherwise
+/// the returned path is not guaranteed to be the cheapest.
+pub fn astar<S, F, I, H, G>(
+    start: S,
+    successors: F,
+    heuristic: H,
+    is_goal: G,
+) -> Option<(Vec<S>, u64)>
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    H: FnMut(&S) -> u64,
+    G: FnMut(&S) -> bool,
+{
+    let (distances, predecessors, goal) = best_first(start, successors, heuristic, is_goal);
+    let goal = goal?;
+    let cost = distances[&goal];
+    Some((reconstruct_path(&predecessors, goal), cost))
+}
+
+type BestFirstResult<S> = (HashMap<S, u64>, HashMap<S, S>, Option<S>);
+
+/// Shared implementation of Dijkstra and A*: expands states in order of cost plus heuristic,
+/// until a goal is expanded or everything reachable has been explored.
+fn best_first<S, F, I, H, G>(
+    start: S,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> BestFirstResult<S>
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    H: FnMut(&S) -> u64,
+    G: FnMut(&S) -> bool,
+{
+    let mut distances: HashMap<S, u64> = HashMap::new();
+    let mut predecessors: HashMap<S, S> = HashMap::new();
+    let mut expanded: HashSet<S> = HashSet::new();
+    // The heap stores indices into `pushed`, so states don't need to implement Ord.
+    let mut pushed: Vec<S> = vec![];
+    let mut heap: BinaryHeap<Reverse<(u64, usize)>> = BinaryHeap::new();
+
+    distances.insert(start.clone(), 0);
+    heap.push(Reverse((heuristic(&start), 0)));
+    pushed.push(start);
+
+    while let Some(Reverse((_, index))) = heap.pop() {
+        let state = pushed[index].clone();
+        if !expanded.insert(state.clone()) {
+            continue;
+        }
+
+        if is_goal(&state) {
+            return (distances, predecessors, Some(state));
+        }
+
+        let distance = distances[&state];
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+            if distances
+                .get(&next)
+                .is_none_or(|&known| next_distance < known)
+            {
+                distances.insert(next.clone(), next_distance);
+                predecessors.insert(next.clone(), state.clone());
+                heap.push(Reverse((next_distance + heuristic(&next), pushed.len())));
+                pushed.push(next);
+            }
+        }
+    }
+
+    (distances, predecessors, None)
+}
+
+/// Counts the distinct paths from `start` to states satisfying `is_goal`, memoizing per state.
+/// A goal state ends a path. The state space must be acyclic, otherwise this doesn't terminate.
+pub fn count_paths<S, F, I, G>(start: S, mut successors: F, mut is_goal: G) -> u64
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut memo: HashMap<S, u64> = HashMap::new();
+    count_paths_from(&start, &mut successors, &mut is_goal, &mut memo)
+}
+
+fn count_paths_from<S, F, I, G>(
+    state: &S,
+    successors: &mut F,
+    is_goal: &mut G,
+    memo: &mut HashMap<S, u64>,
+) -> u64
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    if is_goal(state) {
+        return 1;
+    }
+
+    if let Some(&nr_paths) = memo.get(state) {
+        return nr_paths;
+    }
+
+    let nexts: Vec<S> = successors(state).into_iter().collect();
+    let nr_paths = nexts
+        .iter()
+        .map(|next| count_paths_from(next, successors, is_goal, memo))
+        .sum();
+    memo.insert(state.clone(), nr_paths);
+
+    nr_paths
+}
+
+fn reconstruct_path<S>(predecessors: &HashMap<S, S>, goal: S) -> Vec<S>
+where
+    S: Eq + Hash + Clone,
+{
+    let mut path = vec![goal];
+    while let Some(previous) = predecessors.get(path.last().unwrap()) {
+        path.push(previous.clone());
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point};
+
+    static MAZE: &str = r#"S.#.....
+.##.###.
+....#...
+.####.#.
+......#E
+"#;
+
+    fn open_neighbours(grid: &Grid<char>, p: &Point) -> Vec<Point> {
+        [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .map(|(dx, dy)| p.translate(dx, dy))
+            .filter(|next| next.x >= 0 && next.y >= 0)
+            .filter(|next| grid.at_point(next).is_some_and(|&ch| ch != '#'))
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs_on_grid() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse(MAZE.lines());
+
+        // Act
+        let actual = bfs(Point::new(0, 0), |p| open_neighbours(&grid, p));
+
+        // Assert
+        let end = Point::new(7, 4);
+        assert_eq!(actual.distance(&end), Some(15));
+        let path = actual.path_to(&end).unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!(path.first(), Some(&Point::new(0, 0)));
+        assert_eq!(path.last(), Some(&end));
+        assert!(!actual.is_reachable(&Point::new(2, 0)));
+        assert_eq!(actual.path_to(&Point::new(2, 0)), None);
+    }
+
+    #[test]
+    fn test_bfs_to() {
+        // Act
+        let actual = bfs_to(1, |&n| vec![n * 2, n + 1], |&n| n == 10);
+
+        // Assert
+        assert_eq!(actual, Some(vec![1, 2, 4, 5, 10]));
+    }
+
+    #[test]
+    fn test_bfs_to_unreachable() {
+        // Act
+        let actual = bfs_to(
+            1,
+            |&n| if n < 5 { vec![n + 1] } else { vec![] },
+            |&n| n == 10,
+        );
+
+        // Assert
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn test_dfs() {
+        // Arrange
+        let edges: HashMap<char, Vec<char>> = HashMap::from([
+            ('a', vec!['b', 'c']),
+            ('b', vec!['d']),
+            ('c', vec!['d']),
+            ('d', vec!['a']),
+        ]);
+
+        // Act
+        let actual = dfs('a', |state| edges.get(state).cloned().unwrap_or_default());
+
+        // Assert
+        assert_eq!(actual, vec!['a', 'b', 'd', 'c']);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // Arrange
+        let edges: HashMap<char, Vec<(char, u64)>> = HashMap::from([
+            ('a', vec![('b', 7), ('c', 2)]),
+            ('c', vec![('b', 3), ('d', 10)]),
+            ('b', vec![('d', 1)]),
+        ]);
+
+        // Act
+        let actual = dijkstra('a', |state| edges.get(state).cloned().unwrap_or_default());
+
+        // Assert
+        assert_eq!(actual.distance(&'b'), Some(5));
+        assert_eq!(actual.distance(&'d'), Some(6));
+        assert_eq!(actual.path_to(&'d'), Some(vec!['a', 'c', 'b', 'd']));
+    }
+
+    #[test]
+    fn test_dijkstra_to() {
+        // Act
+        let actual = dijkstra_to(
+            0u64,
+            |&n| {
+                if n < 20 {
+                    vec![(n + 1, 1), (n * 3, 2)]
+                } else {
+                    vec![]
+                }
+            },
+            |&n| n == 18,
+        );
+
+        // Assert
+        assert_eq!(actual, Some((vec![0, 1, 2, 6, 18], 6)));
+    }
+
+    #[test]
+    fn test_astar_on_grid() {
+        // Arrange
+        let grid: Grid<char> = Grid::parse(MAZE.lines());
+        let end = Point::new(7, 4);
+
+        // Act
+        let actual = astar(
+            Point::new(0, 0),
+            |p| open_neighbours(&grid, p).into_iter().map(|next| (next, 1)),
+            |p| p.manhattan_distance(&end),
+            |p| *p == end,
+        );
+
+        // Assert
+        let (path, cost) = actual.unwrap();
+        assert_eq!(cost, 15);
+        assert_eq!(path.len(), 16);
+    }
+
+    #[test]
+    fn test_count_paths() {
+        // Arrange
+        let edges: HashMap<&str, Vec<&str>> = HashMap::from([
+            ("a", vec!["b", "c"]),
+            ("b", vec!["c", "d"]),
+            ("c", vec!["d", "e"]),
+            ("d", vec!["e"]),
+        ]);
+
+        // Act
+        let actual = count_paths(
+            "a",
+            |state| edges.get(state).cloned().unwrap_or_default(),
+            |&s| s == "e",
+        );
+
+        // Assert
+        assert_eq!(actual, 5);
+    }
+
+    #[derive(Debug, PartialEq, Eq, Hash, Clone)]
+    struct FlaggedState {
+        node: usize,
+        seen_waypoint: bool,
+    }
+
+    #[test]
+    fn test_count_paths_with_extra_state() {
+        // Arrange: two paths from 0 to 3, only one of them passes waypoint 2.
+        let edges: Vec<Vec<usize>> = vec![vec![1, 2], vec![3], vec![3], vec![]];
+
+        // Act
+        let actual = count_paths(
+            FlaggedState {
+                node: 0,
+                seen_waypoint: false,
+            },
+            |state| {
+                edges[state.node]
+                    .iter()
+                    .map(|&node| FlaggedState {
+                        node,
+                        seen_waypoint: state.seen_waypoint || node == 2,
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |state| state.node == 3 && state.seen_waypoint,
+        );
+
+        // Assert
+        assert_eq!(actual, 1);
+    }
+}
@@ -0,0 +1,108 @@
+/// A solver for the two parts of a single day, so that every day can be run from one entry point.
+///
+/// The puzzle input is parsed once into `Input`, which both parts share, so parsing can be timed
+/// separately from the parts. Answers are returned as strings, since they differ in type from day
+/// to day.
+pub trait Solution {
+    /// The parsed puzzle input.
+    type Input;
+
+    /// The day of the puzzle calendar this solution belongs to (1-based).
+    fn day(&self) -> u8;
+
+    fn parse(&self, input: &str) -> Self::Input;
+
+    fn part1(&self, input: &Self::Input) -> String;
+
+    fn part2(&self, input: &Self::Input) -> String;
+
+    /// Solves the given part (1 or 2), or returns None if the part doesn't exist.
+    fn solve(&self, part: u8, input: &Self::Input) -> Option<String> {
+        match part {
+            1 => Some(self.part1(input)),
+            2 => Some(self.part2(input)),
+            _ => None,
+        }
+    }
+}
+
+/// Solves a part (1 or 2) of an already parsed input, or returns None if the part doesn't exist.
+pub type ParsedSolution<'a> = Box<dyn Fn(u8) -> Option<String> + 'a>;
+
+/// A [`Solution`] with its `Input` type hidden, so that the solutions of all days fit in one list.
+pub trait DynSolution {
+    fn day(&self) -> u8;
+
+    /// Parses the input and returns the solver of both parts of it.
+    fn parse_input(&self, input: &str) -> ParsedSolution<'_>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        Solution::day(self)
+    }
+
+    fn parse_input(&self, input: &str) -> ParsedSolution<'_> {
+        let input = self.parse(input);
+        Box::new(move |part| self.solve(part, &input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        type Input = String;
+
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn parse(&self, input: &str) -> String {
+            input.to_uppercase()
+        }
+
+        fn part1(&self, input: &String) -> String {
+            format!("1:{input}")
+        }
+
+        fn part2(&self, input: &String) -> String {
+            format!("2:{input}")
+        }
+    }
+
+    #[test]
+    fn test_solve_dispatches_on_part() {
+        // Act
+        let actual_part1 = Echo.solve(1, &"x".to_string());
+        let actual_part2 = Echo.solve(2, &"x".to_string());
+        let actual_part3 = Echo.solve(3, &"x".to_string());
+
+        // Assert
+        assert_eq!(actual_part1, Some("1:x".to_string()));
+        assert_eq!(actual_part2, Some("2:x".to_string()));
+        assert_eq!(actual_part3, None);
+    }
+
+    #[test]
+    fn test_dyn_solution_parses_once_for_both_parts() {
+        // Arrange
+        let solution: &dyn DynSolution = &Echo;
+
+        // Act
+        let parsed = solution.parse_input("x");
+
+        // Assert
+        assert_eq!(solution.day(), 1);
+        assert_eq!(parsed(1), Some("1:X".to_string()));
+        assert_eq!(parsed(2), Some("2:X".to_string()));
+        assert_eq!(parsed(3), None);
+    }
+}
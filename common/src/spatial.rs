@@ -0,0 +1,268 @@
+//! Distance queries over point clouds of [`Coordinate`]s such as `Point` and `Point3d`.
+//!
+//! Points are referred to by their index in the slice passed in, and distances are squared
+//! euclidean distances so everything stays exact.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::{Coordinate, DisjointSet, KdTree};
+
+/// Two points, by index with `a < b`, and the squared distance between them.
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub struct PointPair {
+    pub a: usize,
+    pub b: usize,
+    pub squared_distance: i64,
+}
+
+impl PointPair {
+    fn new(a: usize, b: usize, squared_distance: i64) -> Self {
+        PointPair {
+            a: a.min(b),
+            b: a.max(b),
+            squared_distance,
+        }
+    }
+}
+
+/// Iterator over all pairs of points from closest to farthest, see [`closest_pairs`].
+pub struct ClosestPairs<'a, C> {
+    points: &'a [C],
+    tree: KdTree<C>,
+    /// The nearest neighbours of every point fetched so far, closest first.
+    neighbors: Vec<Vec<usize>>,
+    /// For every point, the position in `neighbors` of its candidate on the heap.
+    next_neighbor: Vec<usize>,
+    /// The candidate of every point as `(squared distance, a, b, point)`, where `(a, b)` is the
+    /// pair and `point` the one of them that proposed it.
+    heap: BinaryHeap<Reverse<(i64, usize, usize, usize)>>,
+}
+
+impl<C> ClosestPairs<'_, C>
+where
+    C: Coordinate,
+{
+    /// Puts the next nearest neighbour of `point` on the heap, fetching twice as many neighbours
+    /// from the tree once the ones fetched so far are used up.
+    fn push_next_neighbor(&mut self, point: usize) {
+        loop {
+            let position = self.next_neighbor[point];
+            if position == self.neighbors[point].len() {
+                let k = (2 * position).max(2).min(self.points.len());
+                if position == k {
+                    return;
+                }
+                self.neighbors[point] = self
+                    .tree
+                    .k_nearest(&self.points[point], k)
+                    .into_iter()
+                    .map(|(index, _)| index)
+                    .collect();
+            }
+
+            let other = self.neighbors[point][position];
+            self.next_neighbor[point] += 1;
+            if other != point {
+                let pair = PointPair::new(point, other, 0);
+                let squared_distance = self.points[point].squared_distance(&self.points[other]);
+                self.heap
+                    .push(Reverse((squared_distance, pair.a, pair.b, point)));
+                return;
+            }
+        }
+    }
+}
+
+impl<C> Iterator for ClosestPairs<'_, C>
+where
+    C: Coordinate,
+{
+    type Item = PointPair;
+
+    fn next(&mut self) -> Option<PointPair> {
+        while let Some(Reverse((squared_distance, a, b, point))) = self.heap.pop() {
+            self.push_next_neighbor(point);
+            // Both points of a pair propose it, only the first one is returned.
+            if point == a {
+                return Some(PointPair::new(a, b, squared_distance));
+            }
+        }
+
+        None
+    }
+}
+
+/// Lazily yields every pair of points in order of increasing distance, with ties ordered by index.
+///
+/// Every point proposes its nearest neighbours one at a time, fetched from a [`KdTree`] in
+/// growing batches, so taking the first few pairs stays far below the `n²` pairs in total.
+pub fn closest_pairs<C>(points: &[C]) -> ClosestPairs<'_, C>
+where
+    C: Coordinate,
+{
+    let mut pairs = ClosestPairs {
+        points,
+        tree: points.iter().copied().collect(),
+        neighbors: vec![vec![]; points.len()],
+        next_neighbor: vec![0; points.len()],
+        heap: BinaryHeap::new(),
+    };
+    for point in 0..points.len() {
+        pairs.push_next_neighbor(point);
+    }
+
+    pairs
+}
+
+/// The edges of a euclidean minimum spanning tree over the points, from shortest to longest.
+///
+/// Runs Borůvka's algorithm on a [`KdTree`], so a few far away points don't force a walk over
+/// almost all pairs. Ties are broken by index exactly like Kruskal's algorithm over all pairs
+/// sorted by `(distance, a, b)` would.
+pub fn minimum_spanning_tree<C>(points: &[C]) -> Vec<PointPair>
+where
+    C: Coordinate,
+{
+    // Every round joins each component to its closest point outside it, which at least halves
+    // the number of components.
+    let tree: KdTree<C> = points.iter().copied().collect();
+    let nr_edges = points.len().saturating_sub(1);
+    let mut components = DisjointSet::new(points.len());
+    let mut edges = Vec::with_capacity(nr_edges);
+    while edges.len() < nr_edges {
+        let groups: Vec<usize> = (0..points.len()).map(|i| components.find(i)).collect();
+        for (squared_distance, a, b) in tree.closest_to_other_groups(&groups).into_iter().flatten()
+        {
+            if components.union(a, b) {
+                edges.push(PointPair::new(a, b, squared_distance));
+            }
+        }
+    }
+
+    edges.sort_by_key(|pair| (pair.squared_distance, pair.a, pair.b));
+    edges
+}
+
+/// Groups the points into `k` clusters by single linkage: repeatedly merging the two closest
+/// clusters, which is the minimum spanning tree without its `k - 1` longest edges. Clusters are
+/// lists of point indices, ordered by their smallest index. Panics if `k` is 0 and there are points.
+pub fn single_linkage_clusters<C>(points: &[C], k: usize) -> Vec<Vec<usize>>
+where
+    C: Coordinate,
+{
+    assert!(k > 0 || points.is_empty(), "Cannot form 0 clusters");
+
+    let mut clusters = DisjointSet::new(points.len());
+    let nr_merges = points.len().saturating_sub(k);
+    for edge in minimum_spanning_tree(points).into_iter().take(nr_merges) {
+        clusters.union(edge.a, edge.b);
+    }
+
+    clusters.components()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{Point, Point3d};
+
+    /// A deterministic cloud of points with plenty of repeated coordinates and distances.
+    fn cloud(n: i64) -> Vec<Point3d> {
+        (0..n)
+            .map(|i| Point3d::new((i * 37) % 23, (i * 11) % 7, (i * 5) % 13))
+            .collect()
+    }
+
+    fn all_pairs_sorted(points: &[Point3d]) -> Vec<PointPair> {
+        let mut pairs = vec![];
+        for a in 0..points.len() {
+            for b in a + 1..points.len() {
+                pairs.push(PointPair::new(a, b, points[a].squared_distance(&points[b])));
+            }
+        }
+        pairs.sort_by_key(|pair| (pair.squared_distance, pair.a, pair.b));
+        pairs
+    }
+
+    #[test]
+    fn test_closest_pairs_matches_sorting_all_pairs() {
+        // Arrange
+        let points = cloud(60);
+
+        // Act
+        let actual: Vec<PointPair> = closest_pairs(&points).collect();
+
+        // Assert
+        assert_eq!(actual, all_pairs_sorted(&points));
+    }
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec![Point::new(4, 4)], vec![])]
+    #[case(vec![Point::new(0, 0), Point::new(10, 0), Point::new(3, 4)], vec![PointPair::new(0, 2, 25), PointPair::new(1, 2, 65), PointPair::new(0, 1, 100)])]
+    fn test_closest_pairs_small(#[case] points: Vec<Point>, #[case] expected: Vec<PointPair>) {
+        // Act
+        let actual: Vec<PointPair> = closest_pairs(&points).collect();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree_matches_kruskal() {
+        // Arrange
+        let points = cloud(60);
+        let mut components = DisjointSet::new(points.len());
+        let expected: Vec<PointPair> = all_pairs_sorted(&points)
+            .into_iter()
+            .filter(|pair| components.union(pair.a, pair.b))
+            .collect();
+
+        // Act
+        let actual = minimum_spanning_tree(&points);
+
+        // Assert
+        assert_eq!(actual.len(), points.len() - 1);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree_of_line() {
+        // Arrange
+        let points = vec![Point::new(0, 0), Point::new(5, 0), Point::new(1, 0)];
+
+        // Act
+        let actual = minimum_spanning_tree(&points);
+
+        // Assert
+        assert_eq!(
+            actual,
+            vec![PointPair::new(0, 2, 1), PointPair::new(1, 2, 16)]
+        );
+    }
+
+    #[rstest]
+    #[case(1, vec![vec![0, 1, 2, 3, 4]])]
+    #[case(2, vec![vec![0, 1, 3], vec![2, 4]])]
+    #[case(3, vec![vec![0, 1], vec![2, 4], vec![3]])]
+    #[case(9, vec![vec![0], vec![1], vec![2], vec![3], vec![4]])]
+    fn test_single_linkage_clusters(#[case] k: usize, #[case] expected: Vec<Vec<usize>>) {
+        // Arrange
+        let points = vec![
+            Point::new(0, 0),
+            Point::new(1, 0),
+            Point::new(20, 20),
+            Point::new(5, 0),
+            Point::new(21, 20),
+        ];
+
+        // Act
+        let actual = single_linkage_clusters(&points, k);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
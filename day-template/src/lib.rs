@@ -0,0 +1,67 @@
+use common::Solution;
+use std::str::Lines;
+
+pub struct Day__DAY_WITH_LEADING_ZEROES__;
+
+impl Solution for Day__DAY_WITH_LEADING_ZEROES__ {
+    type Input = Vec<String>;
+
+    fn day(&self) -> u8 {
+        __DAY__
+    }
+
+    fn parse(&self, input: &str) -> Vec<String> {
+        parse_lines(input.lines())
+    }
+
+    fn part1(&self, lines: &Vec<String>) -> String {
+        solve_part1(lines).to_string()
+    }
+
+    fn part2(&self, lines: &Vec<String>) -> String {
+        solve_part2(lines).to_string()
+    }
+}
+
+fn solve_part1(_lines: &[String]) -> i64 {
+    0
+}
+
+fn solve_part2(_lines: &[String]) -> i64 {
+    0
+}
+
+fn parse_lines(lines: Lines) -> Vec<String> {
+    lines.map(|line| line.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#""#;
+
+    #[test]
+    fn test_solve_part1() {
+        // Arrange
+        let expected: i64 = 0;
+
+        // Act
+        let actual: i64 = solve_part1(&parse_lines(INPUT.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        // Arrange
+        let expected: i64 = 0;
+
+        // Act
+        let actual: i64 = solve_part2(&parse_lines(INPUT.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,47 +1,11 @@
-use common::InputReader;
-use std::str::Lines;
+use common::{InputError, InputReader, Solution};
+use day__DAY_WITH_LEADING_ZEROES__::Day__DAY_WITH_LEADING_ZEROES__;
 
-fn main() {
-    let input_reader: InputReader = InputReader::new(__DAY__);
-    println!("Part 1: {}", solve_part1(input_reader.lines()));
-    println!("Part 2: {}", solve_part2(input_reader.lines()));
-}
-
-fn solve_part1(_lines: Lines) -> i64 {
-    0
-}
-
-fn solve_part2(_lines: Lines) -> i64 {
-    0
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    static INPUT: &str = r#""#;
-
-    #[test]
-    fn test_solve_part1() {
-        // Arrange
-        let expected: i64 = 0;
-
-        // Act
-        let actual: i64 = solve_part1(INPUT.lines());
-
-        // Assert
-        assert_eq!(actual, expected);
-    }
-
-    #[test]
-    fn test_solve_part2() {
-        // Arrange
-        let expected: i64 = 0;
-
-        // Act
-        let actual: i64 = solve_part2(INPUT.lines());
+fn main() -> Result<(), InputError> {
+    let input_reader: InputReader = InputReader::new(__DAY__)?;
+    let input = Day__DAY_WITH_LEADING_ZEROES__.parse(&input_reader.input());
+    println!("Part 1: {}", Day__DAY_WITH_LEADING_ZEROES__.part1(&input));
+    println!("Part 2: {}", Day__DAY_WITH_LEADING_ZEROES__.part2(&input));
 
-        // Assert
-        assert_eq!(actual, expected);
-    }
+    Ok(())
 }
@@ -0,0 +1,150 @@
+use common::Solution;
+use std::str::Lines;
+use substring::Substring;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input: &str) -> Vec<i32> {
+        parse_rotations(input.lines())
+    }
+
+    fn part1(&self, rotations: &Vec<i32>) -> String {
+        solve_part1(rotations).to_string()
+    }
+
+    fn part2(&self, rotations: &Vec<i32>) -> String {
+        solve_part2(rotations).to_string()
+    }
+}
+
+fn solve_part1(rotations: &[i32]) -> i64 {
+    let mut nr_zeroes = 0;
+    let mut dial = 50;
+    for rotation in rotations {
+        dial = (dial + 100 + rotation) % 100;
+        if dial == 0 {
+            nr_zeroes += 1;
+        }
+    }
+
+    nr_zeroes
+}
+
+fn solve_part2(rotations: &[i32]) -> i64 {
+    let mut nr_zeroes = 0;
+    let mut dial = 50;
+    for rotation in rotations {
+        for _i in 0..rotation.abs() {
+            dial = (dial + 100 + rotation.signum()) % 100;
+            if dial == 0 {
+                nr_zeroes += 1;
+            }
+        }
+    }
+
+    nr_zeroes
+}
+
+/// Parses the rotations, turning left as negative and turning right as positive amounts.
+fn parse_rotations(lines: Lines) -> Vec<i32> {
+    lines
+        .map(|line| {
+            let direction_char = line.chars().next().unwrap();
+            let amount: i32 = line.to_string().substring(1, line.len()).parse().unwrap();
+            let sign = if direction_char == 'L' { -1 } else { 1 };
+            sign * amount
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+"#;
+
+    #[test]
+    fn test_solve_part1() {
+        // Arrange
+        let expected: i64 = 3;
+
+        // Act
+        let actual: i64 = solve_part1(&parse_rotations(INPUT.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2_with_r1000() {
+        // Arrange
+        let input: &str = r#"R1000
+"#;
+        let expected: i64 = 10;
+
+        // Act
+        let actual: i64 = solve_part2(&parse_rotations(input.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2_with_not_crossing_boundaries() {
+        // Arrange
+        let input: &str = r#"L50
+R1
+L1
+"#;
+        let expected: i64 = 2;
+
+        // Act
+        let actual: i64 = solve_part2(&parse_rotations(input.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2_with_l1000() {
+        // Arrange
+        let input: &str = r#"L1000
+"#;
+        let expected: i64 = 10;
+
+        // Act
+        let actual: i64 = solve_part2(&parse_rotations(input.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        // Arrange
+        let expected: i64 = 6;
+
+        // Act
+        let actual: i64 = solve_part2(&parse_rotations(INPUT.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,125 @@
+use common::Solution;
+use std::str::Lines;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<i64>>;
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse(&self, input: &str) -> Vec<Vec<i64>> {
+        parse_banks(input.lines())
+    }
+
+    fn part1(&self, banks: &Vec<Vec<i64>>) -> String {
+        solve_part1(banks).to_string()
+    }
+
+    fn part2(&self, banks: &Vec<Vec<i64>>) -> String {
+        solve_part2(banks).to_string()
+    }
+}
+
+fn solve_part1(banks: &[Vec<i64>]) -> i64 {
+    banks.iter().map(|bank| get_max_joltage(bank, 2)).sum()
+}
+
+fn solve_part2(banks: &[Vec<i64>]) -> i64 {
+    banks.iter().map(|bank| get_max_joltage(bank, 12)).sum()
+}
+
+fn get_max_joltage(digits: &[i64], nr_batteries: usize) -> i64 {
+    // Algorithm:
+    // When turning on 12 batteries, we can only look at the first
+    // len - 11 batteries, since we have to be able to select 11 more batteries.
+    // From these 12, we select the battery with the highest joltage.
+    // Then we look at the batteries at the right from the selected battery,
+    // keeping in mind that we can't use the last 10 batteries, since we
+    // have to select 10 more batteries. Etc.
+
+    let mut joltage: i64 = 0;
+    let mut pos = 0;
+    for i in 0..nr_batteries {
+        let joltage_digit = digits
+            .iter()
+            .skip(pos)
+            .take(digits.len() - (nr_batteries - i - 1) - pos)
+            .max()
+            .unwrap();
+        pos += digits[pos..]
+            .iter()
+            .position(|digit| digit == joltage_digit)
+            .unwrap()
+            + 1;
+        joltage = joltage * 10 + *joltage_digit;
+    }
+
+    joltage
+}
+
+fn parse_banks(lines: Lines) -> Vec<Vec<i64>> {
+    lines
+        .map(|line| line.bytes().map(|b| (b - b'0') as i64).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    static INPUT: &str = r#"987654321111111
+811111111111119
+234234234234278
+818181911112111
+"#;
+
+    #[test]
+    fn test_solve_part1() {
+        // Arrange
+        let expected: i64 = 357;
+
+        // Act
+        let actual: i64 = solve_part1(&parse_banks(INPUT.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        // Arrange
+        let expected: i64 = 3121910778619;
+
+        // Act
+        let actual: i64 = solve_part2(&parse_banks(INPUT.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case("987654321111111", 2, 98)]
+    #[case("811111111111119", 2, 89)]
+    #[case("234234234234278", 2, 78)]
+    #[case("818181911112111", 2, 92)]
+    #[case("987654321111111", 12, 987654321111)]
+    #[case("811111111111119", 12, 811111111119)]
+    #[case("234234234234278", 12, 434234234278)]
+    #[case("818181911112111", 12, 888911112111)]
+    fn test_get_max_joltage(
+        #[case] bank: &str,
+        #[case] nr_batteries: usize,
+        #[case] expected: i64,
+    ) {
+        // Act
+        let actual = get_max_joltage(&parse_banks(bank.lines())[0], nr_batteries);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
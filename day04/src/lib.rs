@@ -0,0 +1,102 @@
+use common::{Grid, Point, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse(&self, input: &str) -> Grid<char> {
+        Grid::parse(input.lines())
+    }
+
+    fn part1(&self, grid: &Grid<char>) -> String {
+        solve_part1(grid).to_string()
+    }
+
+    fn part2(&self, grid: &Grid<char>) -> String {
+        solve_part2(grid).to_string()
+    }
+}
+
+fn solve_part1(grid: &Grid<char>) -> usize {
+    get_accessable_rolls(grid).len()
+}
+
+fn solve_part2(grid: &Grid<char>) -> usize {
+    let mut grid: Grid<char> = grid.clone();
+    let mut nr_rolls_removed = 0;
+
+    loop {
+        let accessable_rolls = get_accessable_rolls(&grid);
+        if accessable_rolls.is_empty() {
+            break;
+        }
+        remove_positions(&mut grid, &accessable_rolls);
+        nr_rolls_removed += accessable_rolls.len();
+    }
+
+    nr_rolls_removed
+}
+
+fn get_accessable_rolls(grid: &Grid<char>) -> Vec<Point> {
+    const NEIGHBOUR_LIMIT: usize = 4;
+
+    grid.positions_of(|&ch| ch == '@')
+        .filter(|pos| count_neighbours(grid, pos) < NEIGHBOUR_LIMIT)
+        .collect()
+}
+
+fn remove_positions(grid: &mut Grid<char>, positions: &Vec<Point>) {
+    for pos in positions {
+        grid.remove_at(pos, '.');
+    }
+}
+
+fn count_neighbours(grid: &Grid<char>, pos: &Point) -> usize {
+    grid.neighbors8(pos).filter(|(_, ch)| **ch == '@').count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+"#;
+
+    #[test]
+    fn test_solve_part1() {
+        // Arrange
+        let expected: usize = 13;
+
+        // Act
+        let actual: usize = solve_part1(&Grid::parse(INPUT.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        // Arrange
+        let expected: usize = 43;
+
+        // Act
+        let actual: usize = solve_part2(&Grid::parse(INPUT.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
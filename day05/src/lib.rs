@@ -0,0 +1,152 @@
+use common::{Range, RangeSet, Solution};
+use std::str::Lines;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<Range<i64>>, Vec<i64>);
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse(&self, input: &str) -> (Vec<Range<i64>>, Vec<i64>) {
+        parse_lines(input.lines())
+    }
+
+    fn part1(&self, (fresh_ranges, ingredients): &(Vec<Range<i64>>, Vec<i64>)) -> String {
+        solve_part1(fresh_ranges, ingredients).to_string()
+    }
+
+    fn part2(&self, (fresh_ranges, _ingredients): &(Vec<Range<i64>>, Vec<i64>)) -> String {
+        solve_part2(fresh_ranges).to_string()
+    }
+}
+
+fn solve_part1(fresh_ranges: &[Range<i64>], ingredients: &[i64]) -> i64 {
+    let fresh: RangeSet<i64> = fresh_ranges.iter().copied().collect();
+    ingredients
+        .iter()
+        .filter(|&&ingredient| fresh.contains(ingredient))
+        .count() as i64
+}
+
+fn solve_part2(fresh_ranges: &[Range<i64>]) -> i64 {
+    let fresh: RangeSet<i64> = fresh_ranges.iter().copied().collect();
+    fresh.total_length()
+}
+
+fn parse_lines(lines: Lines) -> (Vec<Range<i64>>, Vec<i64>) {
+    let mut fresh_ranges: Vec<Range<i64>> = vec![];
+    let mut ingredients: Vec<i64> = vec![];
+
+    let mut is_parsing_fresh = true;
+
+    for line in lines {
+        if line.is_empty() {
+            is_parsing_fresh = false;
+        } else if is_parsing_fresh {
+            let range: Range<i64> = line.parse().unwrap();
+            fresh_ranges.push(range);
+        } else {
+            let ingredient: i64 = line.parse().unwrap();
+            ingredients.push(ingredient);
+        }
+    }
+
+    (fresh_ranges, ingredients)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+"#;
+
+    #[test]
+    fn test_solve_part1() {
+        // Arrange
+        let (fresh_ranges, ingredients) = parse_lines(INPUT.lines());
+        let expected: i64 = 3;
+
+        // Act
+        let actual: i64 = solve_part1(&fresh_ranges, &ingredients);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        // Arrange
+        let expected: i64 = 14;
+
+        // Act
+        let actual: i64 = solve_part2(&parse_lines(INPUT.lines()).0);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2_with_complex_ranges() {
+        // Arrange
+        let input: &str = r#"3-5
+4-4
+
+"#;
+        let expected: i64 = 3;
+
+        // Act
+        let actual: i64 = solve_part2(&parse_lines(input.lines()).0);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2_with_more_complex_ranges() {
+        // Arrange
+        let input: &str = r#"3-5
+4-4
+4-4
+
+"#;
+        let expected: i64 = 3;
+
+        // Act
+        let actual: i64 = solve_part2(&parse_lines(input.lines()).0);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        // Act
+        let (actual_ranges, actual_ingredients) = parse_lines(INPUT.lines());
+
+        // Assert
+        let expected_ranges: Vec<Range<i64>> = vec![
+            Range::new(3, 3),
+            Range::new(10, 5),
+            Range::new(16, 5),
+            Range::new(12, 7),
+        ];
+        assert_eq!(actual_ranges, expected_ranges);
+
+        let expected_ingredients: Vec<i64> = vec![1, 5, 8, 11, 17, 32];
+        assert_eq!(actual_ingredients, expected_ingredients);
+    }
+}
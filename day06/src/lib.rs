@@ -0,0 +1,163 @@
+use common::{Grid, Solution};
+use std::str::Lines;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Grid<char>;
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn parse(&self, input: &str) -> Grid<char> {
+        parse_worksheet(input.lines())
+    }
+
+    fn part1(&self, worksheet: &Grid<char>) -> String {
+        solve_part1(worksheet).to_string()
+    }
+
+    fn part2(&self, worksheet: &Grid<char>) -> String {
+        solve_part2(worksheet).to_string()
+    }
+}
+
+fn solve_part1(worksheet: &Grid<char>) -> i64 {
+    let mut sum: i64 = 0;
+    let rows: Vec<String> = worksheet.rows().map(|row| row.iter().collect()).collect();
+    let problems: Vec<Vec<&str>> = rows
+        .iter()
+        .map(|row| row.split_ascii_whitespace().collect())
+        .collect();
+    let operands = problems.last().unwrap();
+
+    for x in 0..problems[0].len() {
+        let operand = operands[x];
+        let mut result = if operand == "*" { 1 } else { 0 };
+        for problem in problems.iter().take(problems.len() - 1) {
+            let number: i64 = problem[x].parse().unwrap();
+            if operand == "*" {
+                result *= number;
+            } else {
+                result += number;
+            }
+        }
+        sum += result;
+    }
+
+    sum
+}
+
+fn solve_part2(worksheet: &Grid<char>) -> i64 {
+    // Algorithm:
+    // The puzzles describes a right-to-left way of parsing but that is not actually needed.
+    // The numbers are simply built up top-to-bottom, with numbers on earlier lines being more significant.
+    // So we can easily built a number by parsing all chars in a column (except for the last line) in the
+    // same way we would parse a left-to-right number: by multiplying by 10 before adding the next digit.
+    // We do this in a left to right way, where every column that has an operand determines the start of
+    // a new problem. Between columns we apply the operand that defined the start of the problem.
+    let mut sum: i64 = 0;
+    let mut current_operand = ' ';
+
+    let mut current_result: i64 = 0;
+    for column in worksheet.columns() {
+        let (operand_of_current_column, digits) = column.split_last().unwrap();
+        if **operand_of_current_column != ' ' {
+            // Done with the previous problem, we need to add the final result to the global sum and
+            // reset for the next problem.
+            current_operand = **operand_of_current_column;
+            sum += current_result;
+            current_result = if current_operand == '*' { 1 } else { 0 };
+        }
+
+        if let Some(current_value) = get_value_from_column(digits) {
+            if current_operand == '*' {
+                current_result *= current_value;
+            } else {
+                current_result += current_value;
+            }
+        }
+    }
+
+    // We didn't finish the last problem, so we do that now.
+    sum += current_result;
+
+    sum
+}
+
+/// Editors may trim the trailing spaces, so short rows are padded to keep the columns aligned.
+fn parse_worksheet(lines: Lines) -> Grid<char> {
+    Grid::parse_padded(lines, ' ')
+}
+
+/// Builds the number from the digits in a column, top to bottom. Returns None for an empty column.
+fn get_value_from_column(digits: &[&char]) -> Option<i64> {
+    let mut current_value: i64 = 0;
+    let mut is_column_with_value = false;
+
+    for optional_number in digits {
+        if **optional_number != ' ' {
+            current_value = current_value * 10 + optional_number.to_digit(10).unwrap() as i64;
+            is_column_with_value = true;
+        }
+    }
+
+    if is_column_with_value {
+        Some(current_value)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+"#;
+
+    #[test]
+    fn test_solve_part1() {
+        // Arrange
+        let expected: i64 = 4277556;
+
+        // Act
+        let actual: i64 = solve_part1(&parse_worksheet(INPUT.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        // Arrange
+        let expected: i64 = 3263827;
+
+        // Act
+        let actual: i64 = solve_part2(&parse_worksheet(INPUT.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2_trimmed_lines() {
+        // Arrange
+        let input: String = INPUT
+            .lines()
+            .map(|line| line.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let expected: i64 = 3263827;
+
+        // Act
+        let actual: i64 = solve_part2(&parse_worksheet(input.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
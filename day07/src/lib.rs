@@ -0,0 +1,142 @@
+use common::{Direction4, Grid, Point, Solution};
+use std::{collections::HashMap, str::Lines};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = (Grid<char>, Point);
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn parse(&self, input: &str) -> (Grid<char>, Point) {
+        parse_manifold(input.lines())
+    }
+
+    fn part1(&self, (manifold, start): &(Grid<char>, Point)) -> String {
+        solve_part1(manifold, *start).to_string()
+    }
+
+    fn part2(&self, (manifold, start): &(Grid<char>, Point)) -> String {
+        solve_part2(manifold, *start).to_string()
+    }
+}
+
+fn solve_part1(manifold: &Grid<char>, start: Point) -> i64 {
+    let mut visited_splitters: HashMap<Point, i64> = HashMap::new();
+
+    follow_beam(start, &mut visited_splitters, manifold);
+
+    visited_splitters.len() as i64
+}
+
+fn solve_part2(manifold: &Grid<char>, start: Point) -> i64 {
+    let mut visited_splitters: HashMap<Point, i64> = HashMap::new();
+
+    follow_beam(start, &mut visited_splitters, manifold)
+}
+
+/// The manifold and the start of the beam.
+fn parse_manifold(lines: Lines) -> (Grid<char>, Point) {
+    let (manifold, specials): (Grid<char>, _) = Grid::parse_with_specials(lines, &['S'], '.');
+    let start: Point = specials[&'S'][0];
+    (manifold, start)
+}
+
+/// Recursively follows a beam through the manifold grid, counting the number of distinct paths.
+///
+/// The beam moves downward (y+1) from the start point and behaves differently based on the character encountered:
+/// - `'.'`: Empty space - the beam continues moving downward
+/// - `'^'`: Splitter - the beam splits into two paths going left (x-1) and right (x+1)
+/// - Out of bounds: Represents the end of a path (returns 1)
+///
+/// # Arguments
+///
+/// * `start` - The current position of the beam
+/// * `visited_splitters` - Cache of previously visited splitters and their total path counts
+/// * `manifold` - The grid containing the beam path layout
+///
+/// # Returns
+///
+/// The total number of distinct paths from this point to the edge(s) of the grid.
+/// When a splitter is encountered, the return value is the sum of paths from both branches.
+/// When reaching the edge of the grid, returns 1 (one path).
+///
+/// # Memoization
+///
+/// Results are cached in `visited_splitters` to avoid recalculating paths from the same splitter.
+fn follow_beam(
+    start: Point,
+    visited_splitters: &mut HashMap<Point, i64>,
+    manifold: &Grid<char>,
+) -> i64 {
+    let next_point = start.step(Direction4::Down, 1);
+    if let Some(ch) = manifold.at_point(&next_point) {
+        if *ch == '.' {
+            return follow_beam(next_point, visited_splitters, manifold);
+        } else if *ch == '^' {
+            if !visited_splitters.contains_key(&next_point) {
+                let left_point = next_point.step(Direction4::Left, 1);
+                let right_point = next_point.step(Direction4::Right, 1);
+                let nr_paths = follow_beam(left_point, visited_splitters, manifold)
+                    + follow_beam(right_point, visited_splitters, manifold);
+                visited_splitters.insert(next_point, nr_paths);
+                return nr_paths;
+            } else {
+                return visited_splitters[&next_point];
+            }
+        }
+    }
+
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+"#;
+
+    #[test]
+    fn test_solve_part1() {
+        // Arrange
+        let (manifold, start) = parse_manifold(INPUT.lines());
+        let expected: i64 = 21;
+
+        // Act
+        let actual: i64 = solve_part1(&manifold, start);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        // Arrange
+        let (manifold, start) = parse_manifold(INPUT.lines());
+        let expected: i64 = 40;
+
+        // Act
+        let actual: i64 = solve_part2(&manifold, start);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,125 @@
+use common::{DisjointSet, Point3d, Solution, closest_pairs, minimum_spanning_tree};
+use std::str::Lines;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Point3d>;
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn parse(&self, input: &str) -> Vec<Point3d> {
+        parse_points(input.lines())
+    }
+
+    fn part1(&self, points: &Vec<Point3d>) -> String {
+        solve_part1(points, 1000).to_string()
+    }
+
+    fn part2(&self, points: &Vec<Point3d>) -> String {
+        solve_part2(points).to_string()
+    }
+}
+
+/// Kruskal's algorithm cut short: connects the closest pairs of junction boxes, even when they are
+/// already in the same circuit, and multiplies the sizes of the three largest circuits.
+fn solve_part1(points: &[Point3d], nr_connections: usize) -> i64 {
+    let mut circuits = DisjointSet::new(points.len());
+    for pair in closest_pairs(points).take(nr_connections) {
+        circuits.union(pair.a, pair.b);
+    }
+
+    circuits.component_sizes().iter().take(3).product::<usize>() as i64
+}
+
+/// The connection that joins all junction boxes into a single circuit is the longest edge of the
+/// minimum spanning tree.
+fn solve_part2(points: &[Point3d]) -> i64 {
+    let last_connection = minimum_spanning_tree(points)
+        .pop()
+        .expect("No solution found!");
+
+    points[last_connection.a].x * points[last_connection.b].x
+}
+
+/// The junction boxes, sorted so that ties between equally distant pairs are broken the same way
+/// for every order of the input.
+fn parse_points(lines: Lines) -> Vec<Point3d> {
+    let mut points: Vec<Point3d> = lines.map(parse_3d_point).collect();
+    points.sort();
+    points
+}
+
+fn parse_3d_point(line: &str) -> Point3d {
+    let parts: Vec<i64> = line
+        .split(',')
+        .map(|s: &str| s.parse::<i64>().unwrap())
+        .collect();
+    Point3d::new(parts[0], parts[1], parts[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+"#;
+
+    #[test]
+    fn test_solve_part1() {
+        // Arrange
+        let expected: i64 = 40;
+
+        // Act
+        let actual: i64 = solve_part1(&parse_points(INPUT.lines()), 10);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        // Arrange
+        let expected: i64 = 25272;
+
+        // Act
+        let actual: i64 = solve_part2(&parse_points(INPUT.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_3d_point() {
+        // Arrange
+        let line = "162,817,812";
+
+        // Act
+        let actual = parse_3d_point(line);
+
+        // Assert
+        let expected = Point3d::new(162, 817, 812);
+        assert_eq!(actual, expected);
+    }
+}
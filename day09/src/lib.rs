@@ -0,0 +1,123 @@
+use common::{Point, Rect, RectilinearPolygon, Solution};
+use std::str::Lines;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Point>;
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn parse(&self, input: &str) -> Vec<Point> {
+        parse_points(input.lines())
+    }
+
+    fn part1(&self, points: &Vec<Point>) -> String {
+        solve_part1(points).to_string()
+    }
+
+    fn part2(&self, points: &Vec<Point>) -> String {
+        solve_part2(points).to_string()
+    }
+}
+
+fn solve_part1(points: &[Point]) -> i64 {
+    generate_surface_areas(points).into_iter().max().unwrap()
+}
+
+fn solve_part2(points: &[Point]) -> i64 {
+    let polygon = RectilinearPolygon::new(points).unwrap();
+    polygon.largest_inscribed_rect().unwrap().area() as i64
+}
+
+fn parse_points(lines: Lines) -> Vec<Point> {
+    lines.map(parse_line).collect()
+}
+
+fn parse_line(line: &str) -> Point {
+    let parts: Vec<i64> = line
+        .split(',')
+        .map(|part| part.parse::<i64>().unwrap())
+        .collect();
+    Point::new(parts[0], parts[1])
+}
+
+fn generate_surface_areas(points: &[Point]) -> Vec<i64> {
+    let mut surface_areas: Vec<i64> = vec![];
+
+    for i in 0..points.len() - 1 {
+        for j in i + 1..points.len() {
+            let surface_area = Rect::new(&points[i], &points[j]).area() as i64;
+            surface_areas.push(surface_area);
+        }
+    }
+
+    surface_areas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+"#;
+
+    #[test]
+    fn test_solve_part1() {
+        // Arrange
+        let expected: i64 = 50;
+
+        // Act
+        let actual: i64 = solve_part1(&parse_points(INPUT.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        // Arrange
+        let expected: i64 = 24;
+
+        // Act
+        let actual: i64 = solve_part2(&parse_points(INPUT.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_line() {
+        // Arrange
+        let line = "7,1";
+
+        // Act
+        let actual: Point = parse_line(line);
+
+        // Assert
+        let expected = Point::new(7, 1);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_generate_surface_areas() {
+        // Arrange
+        let points: Vec<Point> = vec![Point::new(0, 0), Point::new(1, 1), Point::new(2, 2)];
+
+        // Act
+        let actual: Vec<i64> = generate_surface_areas(&points);
+
+        // Assert
+        let expected: Vec<i64> = vec![4, 9, 4];
+        assert_eq!(actual, expected);
+    }
+}
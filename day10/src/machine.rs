@@ -16,14 +16,14 @@ impl Machine {
     }
 
     pub fn new(
-        light_diagram: &Vec<bool>,
-        wiring_schematics: &Vec<Vec<i64>>,
-        joltage_requirements: &Vec<i64>,
+        light_diagram: &[bool],
+        wiring_schematics: &[Vec<i64>],
+        joltage_requirements: &[i64],
     ) -> Machine {
         Machine {
-            light_diagram: light_diagram.clone(),
-            wiring_schematics: wiring_schematics.clone(),
-            joltage_requirements: joltage_requirements.clone(),
+            light_diagram: light_diagram.to_vec(),
+            wiring_schematics: wiring_schematics.to_vec(),
+            joltage_requirements: joltage_requirements.to_vec(),
             lights: vec![false; light_diagram.len()],
         }
     }
@@ -47,7 +47,7 @@ mod tests {
     #[test]
     fn test_push_button() {
         // Arrange
-        let mut machine = Machine::new(&vec![false, true], &vec![vec![1]], &vec![]);
+        let mut machine = Machine::new(&[false, true], &[vec![1]], &[]);
 
         // Act
         let actual: bool = machine.push_button(0);
@@ -0,0 +1,123 @@
+use common::{DirectedGraph, Solution};
+use std::str::Lines;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = DirectedGraph;
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn parse(&self, input: &str) -> DirectedGraph {
+        parse_lines(input.lines())
+    }
+
+    fn part1(&self, graph: &DirectedGraph) -> String {
+        solve_part1(graph).to_string()
+    }
+
+    fn part2(&self, graph: &DirectedGraph) -> String {
+        solve_part2(graph).to_string()
+    }
+}
+
+fn solve_part1(graph: &DirectedGraph) -> i64 {
+    graph
+        .count_possible_paths("you", "out")
+        .unwrap_or_else(|error| panic!("Cannot count the paths from you to out: {error}"))
+}
+
+fn parse_lines(lines: Lines<'_>) -> DirectedGraph {
+    let mut graph = DirectedGraph::new();
+
+    for line in lines {
+        if let Some((from, to_nodes)) = line.split_once(": ") {
+            for to in to_nodes.split_whitespace() {
+                graph.add_edge(from, to);
+            }
+        }
+    }
+
+    graph
+}
+
+fn solve_part2(graph: &DirectedGraph) -> u128 {
+    graph
+        .count_paths_via("svr", "out", &["dac", "fft"])
+        .unwrap_or_else(|error| {
+            panic!("Cannot count the paths from svr via dac and fft to out: {error}")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part1() {
+        // Arrange
+        let input: &str = r#"aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out
+"#;
+        let expected: i64 = 5;
+
+        // Act
+        let actual: i64 = solve_part1(&parse_lines(input.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        // Arrange
+        let input = r#"svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+"#;
+        let expected: u128 = 2;
+
+        // Act
+        let actual: u128 = solve_part2(&parse_lines(input.lines()));
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        // Arrange
+        let input = "ner: ihv mpi bma lls";
+
+        // Act
+        let graph = parse_lines(input.lines());
+
+        // Assert
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.get_node("ner").unwrap().edges.len(), 4);
+        assert!(graph.has_edge("ner", "ihv"));
+        assert!(graph.has_edge("ner", "mpi"));
+        assert!(graph.has_edge("ner", "bma"));
+        assert!(graph.has_edge("ner", "lls"));
+    }
+}
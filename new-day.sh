@@ -33,3 +33,10 @@ if ! grep -q "'day$DAY_WITH_LEADING_ZEROES'" Cargo.toml; then
     echo "Adding day$DAY_WITH_LEADING_ZEROES to workspace"
     sed -i "/^]$/i\\   'day$DAY_WITH_LEADING_ZEROES'," Cargo.toml
 fi
+
+# Register the day with the aoc runner if not already present
+if ! grep -q "^day$DAY_WITH_LEADING_ZEROES = " aoc/Cargo.toml; then
+    echo "Registering day$DAY_WITH_LEADING_ZEROES with the aoc runner"
+    echo "day$DAY_WITH_LEADING_ZEROES = { path = \"../day$DAY_WITH_LEADING_ZEROES\" }" >> aoc/Cargo.toml
+    sed -i "/^    ]$/i\\        Box::new(day$DAY_WITH_LEADING_ZEROES::Day$DAY_WITH_LEADING_ZEROES)," aoc/src/main.rs
+fi